@@ -0,0 +1,49 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use project_euler::problem::{Params, Solution};
+use project_euler::problems::PROBLEMS;
+use std::time::Duration;
+
+/// benchmark every solution of every problem
+///
+/// Each problem is a group named `problem_<number>`, so that the solutions of a problem are
+/// compared side by side in the report. Solutions run with the default parameters.
+///
+/// To benchmark all problems use this
+///
+/// ```rust,ignore
+/// cargo bench --bench problems
+/// ```
+///
+/// To benchmark a single problem, use this
+///
+/// ```rust,ignore
+/// cargo bench --bench problems -- 'problem_3/'
+/// ```
+///
+/// Some solutions take more than a second, so only a few samples are taken. Change
+/// `sample_size`, `measurement_time` and `warm_up_time` as required.
+///
+fn benchmark_problems(c: &mut Criterion) {
+    for problem in PROBLEMS {
+        let params: Params = problem.params(&[]);
+
+        let mut group = c.benchmark_group(format!("problem_{}", problem.number()));
+        for solution in problem.solutions() {
+            let Solution { name, func } = solution;
+            group.bench_function(name, |b| b.iter(|| func(black_box(&params))));
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(
+    name=benches;
+    config = Criterion::default()
+        .sample_size(10)
+        .measurement_time(Duration::from_secs(5))
+        .warm_up_time(Duration::from_secs(1));
+
+    targets =
+        benchmark_problems,
+);
+criterion_main!(benches);
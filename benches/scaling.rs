@@ -0,0 +1,222 @@
+use criterion::measurement::WallTime;
+use criterion::{
+    black_box, criterion_group, criterion_main, AxisScale, BatchSize, BenchmarkGroup, BenchmarkId,
+    Criterion, PlotConfiguration, Throughput,
+};
+use project_euler::maths::*;
+use std::time::Duration;
+
+/// scaling benchmarks of the functions in `maths.rs`
+///
+/// Every group sweeps its input over orders of magnitude, from 10^2 up to 10^8 for the functions
+/// fast enough, and benchmarks the competing implementations of the same thing side by side.
+/// The report of each group has the time and throughput against the input size on a log scale.
+///
+/// To run all scaling benchmarks use this
+///
+/// ```rust,ignore
+/// cargo bench --bench scaling
+/// ```
+///
+/// To run a single group, use this
+///
+/// ```rust,ignore
+/// cargo bench --bench scaling -- 'scaling/primes_upto_n/'
+/// ```
+///
+/// * Change the largest exponent given to `sizes` to sweep further.
+/// * Change `sample_size`, `measurement_time` and `warm_up_time` as required.
+///
+fn benchmark_primes_upto_n(c: &mut Criterion) {
+    let mut group = scaling_group(c, "primes_upto_n");
+    bench_sizes(
+        &mut group,
+        "sieve_of_eratosthenes",
+        sizes(8),
+        sieve_of_eratosthenes,
+    );
+    bench_sizes(&mut group, "segmented_sieve", sizes(8), |n| {
+        segmented_sieve(0, n)
+    });
+    bench_sizes(
+        &mut group,
+        "primes_upto_n_without_sieve",
+        sizes(4),
+        primes_upto_n_without_sieve,
+    );
+    group.finish();
+}
+
+fn benchmark_is_prime(c: &mut Criterion) {
+    let mut group = scaling_group(c, "is_prime");
+    for size in sizes(8) {
+        // The largest prime below `size`, so that every Miller-Rabin round is run.
+        let prime: u128 = (2..=size).rev().find(|&n| is_prime(n)).unwrap();
+
+        group.throughput(Throughput::Elements(1));
+        group.bench_with_input(BenchmarkId::new("is_prime", size), &prime, |b, &prime| {
+            b.iter(|| is_prime(black_box(prime)))
+        });
+    }
+    group.finish();
+}
+
+fn benchmark_divisors(c: &mut Criterion) {
+    let mut group = scaling_group(c, "divisors");
+    bench_sizes(&mut group, "num_divisors", sizes(8), num_divisors);
+    bench_sizes(
+        &mut group,
+        "sum_of_all_divisors",
+        sizes(8),
+        sum_of_all_divisors,
+    );
+    bench_sizes(&mut group, "all_divisors", sizes(8), all_divisors);
+    group.finish();
+}
+
+fn benchmark_prime_factors(c: &mut Criterion) {
+    let mut group = scaling_group(c, "prime_factors");
+    bench_sizes(
+        &mut group,
+        "prime_factors_of_n_with_sieve_as_hashmap",
+        sizes(7),
+        prime_factors_of_n_with_sieve_as_hashmap,
+    );
+    bench_sizes(
+        &mut group,
+        "prime_factors_of_n_without_sieve_as_hashmap",
+        sizes(4),
+        prime_factors_of_n_without_sieve_as_hashmap,
+    );
+    // Products of the two largest primes below `sqrt(size)`, from 10^8 on so that both factors
+    // are above the primes below 1000 removed by trial division, and rho has to split them.
+    let largest_prime_below = |n: u128| (2..=n).rev().find(|&n| is_prime(n)).unwrap();
+    for size in sizes(20).into_iter().skip(6) {
+        let p: u128 = largest_prime_below(int_sqrt(size));
+        let semiprime: u128 = p * largest_prime_below(p - 1);
+
+        group.throughput(Throughput::Elements(1));
+        group.bench_with_input(
+            BenchmarkId::new("prime_factors_of_n_with_pollard_rho_as_hashmap", size),
+            &semiprime,
+            |b, &semiprime| {
+                b.iter(|| prime_factors_of_n_with_pollard_rho_as_hashmap(black_box(semiprime)))
+            },
+        );
+    }
+    bench_sizes(
+        &mut group,
+        "prime_factors_of_n_with_sieve_as_vec",
+        sizes(7),
+        prime_factors_of_n_with_sieve_as_vec,
+    );
+    bench_sizes(
+        &mut group,
+        "prime_factors_of_n_without_sieve_as_vec",
+        sizes(4),
+        prime_factors_of_n_without_sieve_as_vec,
+    );
+    group.finish();
+}
+
+fn benchmark_big_numbers(c: &mut Criterion) {
+    let mut group = scaling_group(c, "big_numbers");
+    bench_digits(
+        &mut group,
+        "add_two_numbers_as_vec",
+        sizes(7),
+        add_two_numbers_as_vec,
+    );
+    bench_digits(
+        &mut group,
+        "multiply_two_numbers_as_vec",
+        sizes(4),
+        multiply_two_numbers_as_vec,
+    );
+    group.finish();
+
+    let mut group = scaling_group(c, "get_power_of_a_number");
+    for size in sizes(3) {
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("get_power_of_a_number", size),
+            &u128_to_vecu8(size),
+            |b, power| {
+                b.iter_batched(
+                    || (vec![2], power.clone()),
+                    |(base, power)| get_power_of_a_number(base, power),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Input sizes 10^2, 10^3, ..., 10^max_exponent.
+fn sizes(max_exponent: u32) -> Vec<u128> {
+    return (2..=max_exponent)
+        .map(|exponent| 10u128.pow(exponent))
+        .collect();
+}
+
+/// A group named `scaling/<name>` with the input sizes on a logarithmic axis.
+fn scaling_group<'a>(c: &'a mut Criterion, name: &str) -> BenchmarkGroup<'a, WallTime> {
+    let mut group = c.benchmark_group(format!("scaling/{}", name));
+    group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+    return group;
+}
+
+/// Benchmarks `func(n)` for every `n` in `sizes`, counting `n` elements per iteration.
+fn bench_sizes<T>(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    sizes: Vec<u128>,
+    func: fn(u128) -> T,
+) {
+    for size in sizes {
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new(name, size), &size, |b, &size| {
+            b.iter(|| func(black_box(size)))
+        });
+    }
+}
+
+/// Benchmarks `func` on two numbers of `n` digits for every `n` in `sizes`, counting `n`
+/// elements per iteration.
+fn bench_digits(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    sizes: Vec<u128>,
+    func: fn(Vec<u8>, Vec<u8>) -> Vec<u8>,
+) {
+    for size in sizes {
+        let num1: Vec<u8> = (0..size).map(|i| (i % 9 + 1) as u8).collect();
+        let num2: Vec<u8> = (0..size).map(|i| (8 - i % 9) as u8 + 1).collect();
+
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new(name, size), &(num1, num2), |b, nums| {
+            b.iter_batched(
+                || nums.clone(),
+                |(num1, num2)| func(num1, num2),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+}
+
+criterion_group!(
+    name=benches;
+    config = Criterion::default()
+        .sample_size(10)
+        .measurement_time(Duration::from_secs(3))
+        .warm_up_time(Duration::from_secs(1));
+
+    targets =
+        benchmark_primes_upto_n,
+        benchmark_is_prime,
+        benchmark_divisors,
+        benchmark_prime_factors,
+        benchmark_big_numbers,
+);
+criterion_main!(benches);
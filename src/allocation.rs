@@ -0,0 +1,161 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static IS_COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// A global allocator that forwards to [`System`] and counts every allocation.
+///
+/// It is only installed by the binary when built with the `count-allocations` feature, which
+/// then calls [`enable`] at the start of `main` :
+///
+/// ```rust,ignore
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+///
+/// fn main() {
+///     allocation::enable();
+///     ...
+/// }
+/// ```
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record_allocation(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+        let live_bytes: usize = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_LIVE_BYTES.fetch_max(live_bytes, Ordering::Relaxed);
+    }
+
+    fn record_deallocation(size: usize) {
+        LIVE_BYTES.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr: *mut u8 = System.alloc(layout);
+        if !ptr.is_null() {
+            CountingAllocator::record_allocation(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr: *mut u8 = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            CountingAllocator::record_allocation(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CountingAllocator::record_deallocation(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr: *mut u8 = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CountingAllocator::record_deallocation(layout.size());
+            CountingAllocator::record_allocation(new_size);
+        }
+        return new_ptr;
+    }
+}
+
+/// Allocations made while running a function.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// Number of allocations, counting every reallocation as one.
+    pub allocations: usize,
+    /// Total bytes allocated, including memory freed before the function returned.
+    pub allocated_bytes: usize,
+    /// Largest number of bytes allocated by the function and alive at the same time.
+    pub peak_bytes: usize,
+}
+
+/// Declares that [`CountingAllocator`] is the global allocator of the running program, so that
+/// [`measure`] reports the allocations it counts.
+///
+/// Only the program installing the allocator can know it, as the allocator can also be called
+/// directly, like the tests do.
+pub fn enable() {
+    IS_COUNTING.store(true, Ordering::Relaxed);
+}
+
+/// Returns whether [`CountingAllocator`] is the global allocator of the running program, as
+/// declared with [`enable`].
+pub fn is_counting() -> bool {
+    return IS_COUNTING.load(Ordering::Relaxed);
+}
+
+/// Runs `func` and returns its result with the allocations it made, or `None` for the
+/// allocations when [`CountingAllocator`] is not the global allocator.
+///
+/// The counters are global, so allocations made by other threads at the same time are counted
+/// too.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::allocation::measure;
+///
+/// let (sum, stats) = measure(|| (1..=10).sum::<u32>());
+///
+/// assert_eq!(sum, 55);
+/// assert_eq!(stats, None);
+/// ```
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocationStats>) {
+    if !is_counting() {
+        return (func(), None);
+    }
+
+    let allocations: usize = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes: usize = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let live_bytes: usize = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_LIVE_BYTES.store(live_bytes, Ordering::Relaxed);
+
+    let result: T = func();
+
+    let stats: AllocationStats = AllocationStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes,
+        peak_bytes: PEAK_LIVE_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(live_bytes),
+    };
+
+    return (result, Some(stats));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting_allocator() {
+        let layout: Layout = Layout::from_size_align(1024, 8).unwrap();
+
+        let allocations: usize = ALLOCATIONS.load(Ordering::Relaxed);
+        let allocated_bytes: usize = ALLOCATED_BYTES.load(Ordering::Relaxed);
+
+        unsafe {
+            let ptr: *mut u8 = CountingAllocator.alloc(layout);
+            let ptr: *mut u8 = CountingAllocator.realloc(ptr, layout, 2048);
+            CountingAllocator.dealloc(ptr, Layout::from_size_align(2048, 8).unwrap());
+        }
+
+        assert_eq!(ALLOCATIONS.load(Ordering::Relaxed) - allocations, 2);
+        assert_eq!(
+            ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes,
+            3072
+        );
+        assert!(PEAK_LIVE_BYTES.load(Ordering::Relaxed) >= 2048);
+        assert!(!is_counting());
+    }
+}
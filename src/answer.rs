@@ -0,0 +1,77 @@
+use std::fmt;
+
+/// The answer returned by a solution.
+///
+/// All the answers are displayed in a single format so that they can be compared, stored and
+/// tested without caring about how a solution computed them.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::answer::Answer;
+///
+/// assert_eq!(Answer::Integer(6857).to_string(), "6857");
+/// assert_eq!(Answer::BigInteger(vec![1, 0, 7, 4]).to_string(), "1074");
+/// assert_eq!(Answer::Text(String::from("2783915460")).to_string(), "2783915460");
+/// assert_eq!(
+///     Answer::Tuple(vec![("a", -61), ("b", 971)]).to_string(),
+///     "a : -61, b : 971"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// An integer that fits in a `u128`.
+    Integer(u128),
+    /// An integer too large for a `u128`, stored as a vector of digits.
+    ///
+    /// If the number is 345, then it is stored as `vec![3, 4, 5]`.
+    BigInteger(Vec<u8>),
+    /// Any answer that is not a number, like a permutation of digits.
+    Text(String),
+    /// A list of labelled values, for solutions that report more than one number.
+    Tuple(Vec<(&'static str, i128)>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::BigInteger(digits) => {
+                let digits: String = digits.iter().map(|&x| (x + b'0') as char).collect();
+                write!(f, "{}", digits)
+            }
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Tuple(values) => {
+                let values: Vec<String> = values
+                    .iter()
+                    .map(|(label, value)| format!("{} : {}", label, value))
+                    .collect();
+                write!(f, "{}", values.join(", "))
+            }
+        };
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        return Answer::Integer(n);
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Answer {
+        return Answer::Integer(n as u128);
+    }
+}
+
+impl From<Vec<u8>> for Answer {
+    fn from(digits: Vec<u8>) -> Answer {
+        return Answer::BigInteger(digits);
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        return Answer::Text(text);
+    }
+}
@@ -0,0 +1,651 @@
+use crate::complexity;
+use crate::history;
+use crate::isolate::Limits;
+use crate::problems;
+use crate::report::Format;
+use crate::timing::Sampling;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Usage message printed by `--help`.
+pub const USAGE: &str = "\
+Solutions to Project Euler problems
+
+Usage:
+    project_euler [OPTIONS] <PROBLEMS>...
+    project_euler [OPTIONS] all
+    project_euler [OPTIONS] verify [PROBLEMS]...
+    project_euler [OPTIONS] compare [PROBLEMS]...
+    project_euler [OPTIONS] complexity <PROBLEMS|FUNCTIONS>...
+    project_euler new <PROBLEM> [TITLE]...
+
+Problems:
+    Problem numbers, comma separated lists or inclusive ranges, e.g. `3`, `3,7,12` or `1-10`.
+    Append `:<VARIANT>` to run a single solution, e.g. `3:2` or `3:solution_2`.
+
+Commands:
+    all        Run every solved problem and print a summary table
+    verify     Compare the answers with files/answer_digests.txt (every problem by default)
+    compare    Compare the times with the latest ones saved in the history file (every problem by
+               default) and fail if a solution got slower by more than the threshold
+    complexity Time the selected solutions, or functions of maths.rs like sieve_of_eratosthenes, at
+               input sizes doubling up to the value of a parameter and estimate their complexity
+    new        Create src/problems/problem_<PROBLEM>.rs with a placeholder solution and register it
+
+Options:
+    --except <PROBLEMS>    Skip the given problems
+    --param <NAME=VALUE>   Override a parameter of the selected problems, can be repeated
+    --variant <SOLUTION>   Run only the given solution of every selected problem, can be repeated
+    --format <FORMAT>      Output format : text (default), json or csv
+    --warm-up <N>          Untimed runs of every solution before the timed ones, 0 by default, or
+                           1 with compare and --save
+    --repeats <N>          Timed runs of every solution, whose median is the time reported, 1 by
+                           default, or 10 with compare and --save
+    --isolate              Run every solution in a child process, stopped after 60 seconds
+    --timeout <SECONDS>    Run every solution in a child process, stopped after SECONDS
+    --memory <MB>          Run every solution in a child process, with at most MB megabytes of memory
+    --save                 Append the times to the history file
+    --history <PATH>       History file, target/euler-history.jsonl by default
+    --threshold <PERCENT>  Slowdown flagged as a regression by compare, 10 by default
+    --scale <NAME>         Parameter varied by complexity, the first scalable one by default
+    --max <N>              Largest input size timed by complexity
+    --steps <K>            Number of input sizes timed by complexity, 8 by default
+    --list                 List the solved problems with their parameters and exit
+    -h, --help             Print this message and exit
+";
+
+/// What the binary has been asked to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    List,
+    /// Run the selected problems and print their answers.
+    Run,
+    /// Run the selected problems and compare their answers with the known digests.
+    Verify,
+    /// Run the selected problems and compare their times with the history file.
+    Compare,
+    /// Create and register the module of a new problem.
+    New,
+    /// Time the selected solutions and functions at growing input sizes and estimate their
+    /// complexity.
+    Complexity,
+}
+
+/// Parsed command line arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub command: Command,
+    /// Selected problems, in the order they were given, without duplicates.
+    pub problems: Vec<u32>,
+    /// Whether to print a summary table at the end of the run, set by `all`.
+    pub summary: bool,
+    pub format: Format,
+    /// Parameter overrides given with `--param`, in the order they were given.
+    pub params: Vec<(String, u128)>,
+    /// Solutions to run, as problem number and solution name. Problems without any entry run
+    /// every solution.
+    pub variants: Vec<(u32, String)>,
+    /// Number of untimed and timed runs of every solution.
+    pub sampling: Sampling,
+    /// Limits of the child processes the solutions run in, or `None` to run them in this
+    /// process.
+    pub limits: Option<Limits>,
+    /// Title of the problem created by `new`.
+    pub title: String,
+    /// Whether to append the times of the run to the history file, set by `--save`.
+    pub save: bool,
+    pub history: PathBuf,
+    /// Percentage by which a solution must get slower to be flagged by `compare`.
+    pub threshold: u32,
+    /// Functions of [`complexity::FUNCTIONS`] selected by `complexity`.
+    pub functions: Vec<String>,
+    /// Parameter varied by `complexity`, or `None` for the first parameter of every problem.
+    pub scale: Option<String>,
+    /// Largest input size timed by `complexity`, or `None` for the value of the parameter.
+    pub max: Option<u128>,
+    /// Number of input sizes timed by `complexity`.
+    pub steps: usize,
+}
+
+impl Options {
+    fn new(command: Command) -> Options {
+        return Options {
+            command,
+            problems: Vec::new(),
+            summary: false,
+            format: Format::Text,
+            params: Vec::new(),
+            variants: Vec::new(),
+            sampling: Sampling::ONCE,
+            limits: None,
+            title: String::new(),
+            save: false,
+            history: history::default_path(),
+            threshold: history::DEFAULT_THRESHOLD,
+            functions: Vec::new(),
+            scale: None,
+            max: None,
+            steps: complexity::DEFAULT_STEPS,
+        };
+    }
+
+    /// Returns the names of the solutions of `problem` to run, which is empty when every
+    /// solution should be run.
+    pub fn variants_of(&self, problem: u32) -> Vec<String> {
+        return self
+            .variants
+            .iter()
+            .filter(|(problem_number, _)| *problem_number == problem)
+            .map(|(_, name)| name.clone())
+            .collect();
+    }
+}
+
+/// Parses a selection of problems, which is a comma separated list of problem numbers or
+/// inclusive ranges.
+///
+/// ### Arguments
+///
+/// * `selection` : `&str` - The selection, like `3`, `3,7,12` or `1-10,14`.
+///
+/// ### Returns
+///
+/// * `Result<Vec<u32>, String>` - The selected problem numbers in order, or a message describing
+///   the invalid part of the selection.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::cli::parse_selection as f;
+///
+/// assert_eq!(f("3"), Ok(vec![3]));
+/// assert_eq!(f("3,7,12"), Ok(vec![3, 7, 12]));
+/// assert_eq!(f("1-4"), Ok(vec![1, 2, 3, 4]));
+/// assert_eq!(f("1-3,10"), Ok(vec![1, 2, 3, 10]));
+///
+/// assert!(f("").is_err());
+/// assert!(f("three").is_err());
+/// assert!(f("4-1").is_err());
+/// assert!(f("1-").is_err());
+/// ```
+pub fn parse_selection(selection: &str) -> Result<Vec<u32>, String> {
+    let mut problem_numbers: Vec<u32> = Vec::new();
+
+    for part in selection.split(',') {
+        let part: &str = part.trim();
+
+        let parse = |num: &str| -> Result<u32, String> {
+            return num
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| format!("invalid problem number `{}`", num));
+        };
+
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+
+                if start > end {
+                    return Err(format!("invalid range `{}`", part));
+                }
+
+                problem_numbers.extend(start..=end);
+            }
+            None => problem_numbers.push(parse(part)?),
+        }
+    }
+
+    return Ok(problem_numbers);
+}
+
+/// Parses a solution variant, given either by its index starting from 1 or by its name.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::cli::parse_variant as f;
+///
+/// assert_eq!(f("2"), Ok(String::from("solution_2")));
+/// assert_eq!(f("solution_2"), Ok(String::from("solution_2")));
+///
+/// assert!(f("").is_err());
+/// assert!(f("0").is_err());
+/// ```
+pub fn parse_variant(variant: &str) -> Result<String, String> {
+    let variant: &str = variant.trim();
+
+    if variant.is_empty() {
+        return Err(String::from("missing solution variant"));
+    }
+
+    return match variant.parse::<u32>() {
+        Ok(0) => Err(format!("invalid solution variant `{}`", variant)),
+        Ok(index) => Ok(format!("solution_{}", index)),
+        Err(_) => Ok(variant.to_string()),
+    };
+}
+
+/// Parses the arguments of `new`, which are the problem number followed by the words of its
+/// title.
+fn parse_new_args(args: &[String]) -> Result<Options, String> {
+    let number: u32 = match args.first().map(|arg| arg.parse::<u32>()) {
+        Some(Ok(number)) if number > 0 => number,
+        Some(_) => return Err(format!("invalid problem number `{}`", args[0])),
+        None => return Err(String::from("no problem given")),
+    };
+
+    if problems::get(number).is_some() {
+        return Err(format!("problem {} already exists", number));
+    }
+
+    let mut options: Options = Options::new(Command::New);
+    options.problems = vec![number];
+    options.title = match args.len() {
+        1 => format!("Problem {}", number),
+        _ => args[1..].join(" "),
+    };
+
+    return Ok(options);
+}
+
+/// Parses a positive number given to the option `name`.
+fn parse_positive(name: &str, value: &str) -> Result<u64, String> {
+    return match value.parse::<u64>() {
+        Ok(value) if value > 0 => Ok(value),
+        _ => Err(format!("invalid value `{}` for `{}`", value, name)),
+    };
+}
+
+/// Parses a parameter override of the form `name=value`.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::cli::parse_param as f;
+///
+/// assert_eq!(f("limit=5000000"), Ok((String::from("limit"), 5000000)));
+/// assert_eq!(f("limit=5_000_000"), Ok((String::from("limit"), 5000000)));
+///
+/// assert!(f("limit").is_err());
+/// assert!(f("=5").is_err());
+/// assert!(f("limit=-1").is_err());
+/// ```
+pub fn parse_param(param: &str) -> Result<(String, u128), String> {
+    let (name, value) = match param.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => (name.trim(), value.trim()),
+        _ => {
+            return Err(format!(
+                "invalid parameter `{}`, expected NAME=VALUE",
+                param
+            ))
+        }
+    };
+
+    return match value.replace('_', "").parse::<u128>() {
+        Ok(value) => Ok((name.to_string(), value)),
+        Err(_) => Err(format!(
+            "invalid value `{}` for parameter `{}`",
+            value, name
+        )),
+    };
+}
+
+/// Parses the command line arguments, without the name of the binary.
+///
+/// Selected problems that are not solved, and parameters that none of the selected problems
+/// declare, are reported as an error.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::cli::{parse_args, Command};
+/// use project_euler::problems;
+/// use project_euler::report::Format;
+/// use project_euler::timing::Sampling;
+/// use std::time::Duration;
+///
+/// let args = |s: &str| -> Vec<String> { s.split_whitespace().map(String::from).collect() };
+///
+/// let options = parse_args(&args("1-5 --except 2,4 --format csv")).unwrap();
+/// assert_eq!(options.command, Command::Run);
+/// assert_eq!(options.problems, vec![1, 3, 5]);
+/// assert_eq!(options.format, Format::Csv);
+///
+/// let options = parse_args(&args("all --except 14")).unwrap();
+/// assert!(options.summary);
+/// assert!(!options.problems.contains(&14));
+///
+/// assert_eq!(parse_args(&args("verify 3")).unwrap().command, Command::Verify);
+/// assert_eq!(parse_args(&args("--list")).unwrap().command, Command::List);
+/// assert_eq!(parse_args(&args("3 --help")).unwrap().command, Command::Help);
+///
+/// assert!(parse_args(&args("")).is_err());
+/// assert!(parse_args(&args("three")).is_err());
+/// assert!(parse_args(&args("3 --frobnicate")).is_err());
+/// assert!(parse_args(&args("3 --format")).is_err());
+/// assert!(parse_args(&args("1000")).is_err());
+///
+/// let options = parse_args(&args("14 --param limit=5000000")).unwrap();
+/// assert_eq!(options.params, vec![(String::from("limit"), 5000000)]);
+///
+/// assert!(parse_args(&args("14 --param size=3")).is_err());
+/// assert!(parse_args(&args("24 --param position=0")).is_err());
+/// assert!(parse_args(&args("28 --param side_length=1000")).is_err());
+/// assert!(parse_args(&args("11,13 --param n_adjacent=30")).is_err());
+/// assert!(parse_args(&args("verify 14 --param limit=10")).is_err());
+///
+/// let options = parse_args(&args("3:2 7")).unwrap();
+/// assert_eq!(options.problems, vec![3, 7]);
+/// assert_eq!(options.variants_of(3), vec![String::from("solution_2")]);
+/// assert!(options.variants_of(7).is_empty());
+///
+/// let options = parse_args(&args("3 7 --variant solution_1")).unwrap();
+/// assert_eq!(options.variants_of(7), vec![String::from("solution_1")]);
+///
+/// assert!(parse_args(&args("3:4")).is_err());
+/// assert!(parse_args(&args("3 7 --variant solution_2")).is_err());
+///
+/// let options = parse_args(&args("3 --warm-up 2 --repeats 20")).unwrap();
+/// assert_eq!(options.sampling.warm_up, 2);
+/// assert_eq!(options.sampling.repeats, 20);
+///
+/// assert_eq!(parse_args(&args("3")).unwrap().sampling, Sampling::ONCE);
+/// assert_eq!(parse_args(&args("compare 3")).unwrap().sampling, Sampling::default());
+/// assert_eq!(parse_args(&args("3 --save --repeats 3")).unwrap().sampling.repeats, 3);
+/// assert!(parse_args(&args("3 --repeats 0")).is_err());
+///
+/// assert!(parse_args(&args("3")).unwrap().limits.is_none());
+///
+/// let limits = parse_args(&args("3 --timeout 5 --memory 512")).unwrap().limits.unwrap();
+/// assert_eq!(limits.timeout, Duration::from_secs(5));
+/// assert_eq!(limits.memory, Some(512 * 1024 * 1024));
+///
+/// let limits = parse_args(&args("3 --isolate")).unwrap().limits.unwrap();
+/// assert_eq!(limits.timeout, Duration::from_secs(60));
+/// assert_eq!(limits.memory, None);
+///
+/// assert!(parse_args(&args("3 --timeout 0")).is_err());
+/// assert!(parse_args(&args("3 --memory 17592186044416")).is_err());
+///
+/// let options = parse_args(&args("new 32 Pandigital Products")).unwrap();
+/// assert_eq!(options.command, Command::New);
+/// assert_eq!(options.problems, vec![32]);
+/// assert_eq!(options.title, "Pandigital Products");
+///
+/// assert_eq!(parse_args(&args("new 32")).unwrap().title, "Problem 32");
+/// assert!(parse_args(&args("new 3")).is_err());
+/// assert!(parse_args(&args("new")).is_err());
+///
+/// let options = parse_args(&args("compare 3 --threshold 25 --save")).unwrap();
+/// assert_eq!(options.command, Command::Compare);
+/// assert_eq!(options.problems, vec![3]);
+/// assert_eq!(options.threshold, 25);
+/// assert!(options.save);
+///
+/// assert_eq!(parse_args(&args("compare")).unwrap().problems.len(), problems::PROBLEMS.len());
+/// assert!(parse_args(&args("verify --save")).is_err());
+///
+/// let options = parse_args(&args("complexity 14 sieve_of_eratosthenes --steps 5")).unwrap();
+/// assert_eq!(options.command, Command::Complexity);
+/// assert_eq!(options.problems, vec![14]);
+/// assert_eq!(options.functions, vec![String::from("sieve_of_eratosthenes")]);
+/// assert_eq!(options.steps, 5);
+///
+/// assert!(parse_args(&args("complexity")).is_err());
+/// assert!(parse_args(&args("complexity 12")).is_err());
+/// assert!(parse_args(&args("complexity 14 --scale size")).is_err());
+/// assert!(parse_args(&args("complexity 11")).is_err());
+/// assert!(parse_args(&args("complexity 16 --scale base")).is_err());
+/// assert!(parse_args(&args("complexity 28")).is_ok());
+/// assert!(parse_args(&args("complexity is_prime")).is_err());
+/// assert!(parse_args(&args("complexity 14 --steps 1")).is_err());
+/// ```
+pub fn parse_args(args: &[String]) -> Result<Options, String> {
+    if args.first().is_some_and(|arg| arg == "new") {
+        return parse_new_args(&args[1..]);
+    }
+
+    let mut command: Command = Command::Run;
+    let mut selected: Vec<u32> = Vec::new();
+    let mut excluded: Vec<u32> = Vec::new();
+    let mut summary: bool = false;
+    let mut format: Format = Format::Text;
+    let mut params: Vec<(String, u128)> = Vec::new();
+    let mut selected_variants: Vec<(u32, String)> = Vec::new();
+    let mut variant_names: Vec<String> = Vec::new();
+    let mut warm_up: Option<usize> = None;
+    let mut repeats: Option<usize> = None;
+    let mut limits: Option<Limits> = None;
+    let mut save: bool = false;
+    let mut history_path: PathBuf = history::default_path();
+    let mut threshold: u32 = history::DEFAULT_THRESHOLD;
+    let mut functions: Vec<String> = Vec::new();
+    let mut scale: Option<String> = None;
+    let mut max: Option<u128> = None;
+    let mut steps: usize = complexity::DEFAULT_STEPS;
+
+    let mut args = args.iter();
+    let mut is_first: bool = true;
+
+    while let Some(arg) = args.next() {
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) if arg.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+
+        let mut value = || -> Result<String, String> {
+            return match inline_value.clone().or_else(|| args.next().cloned()) {
+                Some(value) => Ok(value),
+                None => Err(format!("missing value for `{}`", name)),
+            };
+        };
+
+        match name {
+            "-h" | "--help" => return Ok(Options::new(Command::Help)),
+            "--list" => command = Command::List,
+            "--except" => excluded.extend(parse_selection(&value()?)?),
+            "--format" => format = value()?.parse::<Format>()?,
+            "--param" => params.push(parse_param(&value()?)?),
+            "--variant" => variant_names.push(parse_variant(&value()?)?),
+            "--save" => save = true,
+            "--history" => history_path = PathBuf::from(value()?),
+            "--threshold" => {
+                let value: String = value()?;
+                threshold = value
+                    .parse::<u32>()
+                    .map_err(|_| format!("invalid value `{}` for `{}`", value, name))?;
+            }
+            "--warm-up" => {
+                let value: String = value()?;
+                warm_up = Some(
+                    value
+                        .parse::<usize>()
+                        .map_err(|_| format!("invalid value `{}` for `{}`", value, name))?,
+                );
+            }
+            "--repeats" => repeats = Some(parse_positive(name, &value()?)? as usize),
+            "--scale" => scale = Some(value()?),
+            "--max" => {
+                let value: String = value()?;
+                max = Some(parse_positive(name, &value.replace('_', ""))? as u128);
+            }
+            "--steps" => {
+                let value: String = value()?;
+                steps = match value.parse::<usize>() {
+                    Ok(steps) if steps >= 2 => steps,
+                    _ => return Err(format!("invalid value `{}` for `{}`", value, name)),
+                };
+            }
+            "--isolate" => {
+                limits.get_or_insert_with(Limits::default);
+            }
+            "--timeout" => {
+                let seconds: u64 = parse_positive(name, &value()?)?;
+                limits.get_or_insert_with(Limits::default).timeout = Duration::from_secs(seconds);
+            }
+            "--memory" => {
+                let value: String = value()?;
+                let bytes: u64 = parse_positive(name, &value)?
+                    .checked_mul(1024 * 1024)
+                    .ok_or_else(|| format!("invalid value `{}` for `{}`", value, name))?;
+                limits.get_or_insert_with(Limits::default).memory = Some(bytes);
+            }
+            "all" if is_first => {
+                summary = true;
+                selected.extend(problems::PROBLEMS.iter().map(|problem| problem.number()));
+            }
+            "verify" if is_first => command = Command::Verify,
+            "compare" if is_first => command = Command::Compare,
+            "complexity" if is_first => command = Command::Complexity,
+            _ if command == Command::Complexity && complexity::get_function(name).is_some() => {
+                functions.push(name.to_string());
+            }
+            _ if name.starts_with('-') => return Err(format!("unknown option `{}`", name)),
+            _ => match name.split_once(':') {
+                Some((selection, variant)) => {
+                    let variant: String = parse_variant(variant)?;
+                    for problem_number in parse_selection(selection)? {
+                        selected.push(problem_number);
+                        selected_variants.push((problem_number, variant.clone()));
+                    }
+                }
+                None => selected.extend(parse_selection(name)?),
+            },
+        }
+
+        is_first = false;
+    }
+
+    if matches!(command, Command::Verify | Command::Compare) && selected.is_empty() {
+        selected.extend(problems::PROBLEMS.iter().map(|problem| problem.number()));
+    }
+
+    if command == Command::Run && selected.is_empty() {
+        return Err(String::from("no problems given"));
+    }
+
+    if command == Command::Complexity && selected.is_empty() && functions.is_empty() {
+        return Err(String::from("no problems or functions given"));
+    }
+
+    let mut problem_numbers: Vec<u32> = Vec::new();
+    for problem_number in selected {
+        if !excluded.contains(&problem_number) && !problem_numbers.contains(&problem_number) {
+            problem_numbers.push(problem_number);
+        }
+    }
+
+    let unsolved: Vec<String> = problem_numbers
+        .iter()
+        .filter(|&&problem_number| problems::get(problem_number).is_none())
+        .map(|problem_number: &u32| problem_number.to_string())
+        .collect();
+
+    if !unsolved.is_empty() {
+        return Err(format!("problems not solved : {}", unsolved.join(", ")));
+    }
+
+    if command == Command::Verify && !params.is_empty() {
+        return Err(String::from("--param cannot be used with verify"));
+    }
+
+    if command == Command::Verify && save {
+        return Err(String::from("--save cannot be used with verify"));
+    }
+
+    if command == Command::Complexity {
+        for &problem_number in &problem_numbers {
+            problems::get(problem_number)
+                .unwrap()
+                .scale_parameter(scale.as_deref())?;
+        }
+    }
+
+    for (name, _) in &params {
+        let is_declared: bool = problem_numbers.iter().any(|&problem_number| {
+            return problems::get(problem_number)
+                .unwrap()
+                .parameters()
+                .iter()
+                .any(|parameter| parameter.name == name);
+        });
+
+        if !is_declared {
+            return Err(format!(
+                "none of the selected problems has a parameter `{}`",
+                name
+            ));
+        }
+    }
+
+    for &problem_number in &problem_numbers {
+        let problem = problems::get(problem_number).unwrap();
+
+        problem
+            .validate(&problem.params(&params))
+            .map_err(|message: String| format!("problem {} : {}", problem_number, message))?;
+    }
+
+    let default_sampling: Sampling = if command == Command::Compare || save {
+        Sampling::default()
+    } else {
+        Sampling::ONCE
+    };
+    let sampling: Sampling = Sampling {
+        warm_up: warm_up.unwrap_or(default_sampling.warm_up),
+        repeats: repeats.unwrap_or(default_sampling.repeats),
+    };
+
+    let mut variants: Vec<(u32, String)> = Vec::new();
+    let requested_variants = problem_numbers
+        .iter()
+        .flat_map(|&problem_number| {
+            return variant_names
+                .iter()
+                .map(move |name: &String| (problem_number, name.clone()));
+        })
+        .chain(selected_variants);
+
+    for (problem_number, name) in requested_variants {
+        if !problem_numbers.contains(&problem_number)
+            || variants.contains(&(problem_number, name.clone()))
+        {
+            continue;
+        }
+
+        let is_solution: bool = problems::get(problem_number)
+            .unwrap()
+            .solutions()
+            .iter()
+            .any(|solution| solution.name == name);
+
+        if !is_solution {
+            return Err(format!(
+                "problem {} has no solution `{}`",
+                problem_number, name
+            ));
+        }
+
+        variants.push((problem_number, name));
+    }
+
+    return Ok(Options {
+        command,
+        problems: problem_numbers,
+        summary,
+        format,
+        params,
+        variants,
+        sampling,
+        limits,
+        title: String::new(),
+        save,
+        history: history_path,
+        threshold,
+        functions,
+        scale,
+        max,
+        steps,
+    });
+}
@@ -0,0 +1,227 @@
+use crate::maths;
+use crate::timing;
+use std::fmt;
+use std::hint;
+use std::time::Duration;
+
+/// Number of input sizes a function is timed at by default.
+pub const DEFAULT_STEPS: usize = 8;
+
+/// Number of timed runs at every input size, of which the fastest is kept.
+pub const REPEATS: usize = 3;
+
+/// Estimated time complexity of a function, from timings at several input sizes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimate {
+    /// Exponent `k` of the fitted `t = c * n^k`.
+    pub exponent: f64,
+    /// Coefficient of determination of the fit, where 1 is a perfect fit.
+    pub r_squared: f64,
+}
+
+impl fmt::Display for Estimate {
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::complexity::Estimate;
+    ///
+    /// let estimate: Estimate = Estimate { exponent: 1.1042, r_squared: 0.99 };
+    ///
+    /// assert_eq!(estimate.to_string(), "O(n^1.10)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "O(n^{:.2})", self.exponent);
+    }
+}
+
+/// Returns `steps` input sizes growing by a factor of 2, with `max` being the largest.
+///
+/// Sizes smaller than 1 are left out, and so are duplicates.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::complexity::geometric_sizes as f;
+///
+/// assert_eq!(f(1000, 4), vec![125, 250, 500, 1000]);
+/// assert_eq!(f(3, 4), vec![1, 3]);
+/// assert_eq!(f(0, 4), vec![]);
+/// ```
+pub fn geometric_sizes(max: u128, steps: usize) -> Vec<u128> {
+    let mut sizes: Vec<u128> = (0..steps)
+        .rev()
+        .map(|step: usize| max.checked_shr(step as u32).unwrap_or(0))
+        .filter(|&size| size > 0)
+        .collect();
+    sizes.dedup();
+
+    return sizes;
+}
+
+/// Fits `t = c * n^k` to the timings by a least squares regression of `ln t` on `ln n`.
+///
+/// ### Arguments
+///
+/// * `timings` : `&[(u128, Duration)]` - Input sizes with the time taken at each size.
+///
+/// ### Returns
+///
+/// * `Option<Estimate>` - The estimate, or `None` if there are fewer than two distinct sizes or a
+///   time is 0.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::complexity::fit;
+/// use std::time::Duration;
+///
+/// let quadratic: Vec<(u128, Duration)> = [10, 20, 40, 80]
+///     .map(|n: u128| (n, Duration::from_nanos((n * n) as u64)))
+///     .to_vec();
+/// let estimate = fit(&quadratic).unwrap();
+///
+/// assert!((estimate.exponent - 2.0).abs() < 1e-9);
+/// assert!((estimate.r_squared - 1.0).abs() < 1e-9);
+///
+/// assert_eq!(fit(&[(10, Duration::from_nanos(5))]), None);
+/// assert_eq!(fit(&[(10, Duration::ZERO), (20, Duration::from_nanos(5))]), None);
+/// ```
+pub fn fit(timings: &[(u128, Duration)]) -> Option<Estimate> {
+    if timings.iter().any(|(_, elapsed)| elapsed.is_zero()) {
+        return None;
+    }
+
+    let points: Vec<(f64, f64)> = timings
+        .iter()
+        .map(|(size, elapsed)| ((*size as f64).ln(), elapsed.as_secs_f64().ln()))
+        .collect();
+
+    let n: f64 = points.len() as f64;
+    let mean_x: f64 = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y: f64 = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let sxx: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    let sxy: f64 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let syy: f64 = points.iter().map(|(_, y)| (y - mean_y).powi(2)).sum();
+
+    if sxx == 0.0 {
+        return None;
+    }
+
+    let exponent: f64 = sxy / sxx;
+    let r_squared: f64 = if syy == 0.0 {
+        1.0
+    } else {
+        (sxy * sxy) / (sxx * syy)
+    };
+
+    return Some(Estimate {
+        exponent,
+        r_squared,
+    });
+}
+
+/// Times `func` at every size in `sizes`, keeping the fastest of [`REPEATS`] runs after one
+/// untimed run.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::complexity::time_sizes;
+///
+/// let timings = time_sizes(&[10, 100], |n: u128| (1..=n).sum::<u128>());
+///
+/// assert_eq!(timings.len(), 2);
+/// assert_eq!(timings[1].0, 100);
+/// ```
+pub fn time_sizes<T>(sizes: &[u128], mut func: impl FnMut(u128) -> T) -> Vec<(u128, Duration)> {
+    return sizes
+        .iter()
+        .map(|&size: &u128| {
+            let (_, samples) = timing::sample(1, REPEATS, || func(size));
+            return (size, samples.into_iter().min().unwrap());
+        })
+        .collect();
+}
+
+/// A function of [`maths`] whose complexity can be estimated by the `complexity` command.
+pub struct Function {
+    pub name: &'static str,
+    /// Largest input size timed by default, small enough to take about a second.
+    pub max_size: u128,
+    pub func: fn(u128),
+}
+
+/// Functions of [`maths`] that take the input size as their only argument.
+pub static FUNCTIONS: &[Function] = &[
+    Function {
+        name: "sieve_of_eratosthenes",
+        max_size: 10_000_000,
+        func: |n: u128| {
+            hint::black_box(maths::sieve_of_eratosthenes(n));
+        },
+    },
+    Function {
+        name: "segmented_sieve",
+        max_size: 10_000_000,
+        func: |n: u128| {
+            hint::black_box(maths::segmented_sieve(0, n));
+        },
+    },
+    Function {
+        name: "primes_upto_n_without_sieve",
+        max_size: 100_000,
+        func: |n: u128| {
+            hint::black_box(maths::primes_upto_n_without_sieve(n));
+        },
+    },
+    Function {
+        name: "prime_factors_of_n_with_sieve_as_hashmap",
+        max_size: 10_000_000,
+        func: |n: u128| {
+            hint::black_box(maths::prime_factors_of_n_with_sieve_as_hashmap(n));
+        },
+    },
+    Function {
+        name: "smallest_prime_factor_sieve",
+        max_size: 1_000_000,
+        func: |n: u128| {
+            let sieve: maths::SmallestPrimeFactorSieve = maths::SmallestPrimeFactorSieve::new(n);
+
+            for i in 0..=n {
+                hint::black_box(sieve.factorize(i));
+            }
+        },
+    },
+    Function {
+        name: "prime_factors_of_n_without_sieve_as_hashmap",
+        max_size: 100_000,
+        func: |n: u128| {
+            hint::black_box(maths::prime_factors_of_n_without_sieve_as_hashmap(n));
+        },
+    },
+    Function {
+        name: "first_n_primes",
+        max_size: 10_000,
+        func: |n: u128| {
+            hint::black_box(maths::first_n_primes(n));
+        },
+    },
+];
+
+/// Returns the function of [`FUNCTIONS`] called `name`, if there is one.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::complexity::get_function;
+///
+/// assert_eq!(get_function("sieve_of_eratosthenes").unwrap().max_size, 10_000_000);
+/// assert!(get_function("is_prime").is_none());
+/// ```
+pub fn get_function(name: &str) -> Option<&'static Function> {
+    return FUNCTIONS.iter().find(|function| function.name == name);
+}
@@ -0,0 +1,264 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Directory of the data files, relative to the root of the crate.
+pub const FILES_DIR: &str = "files";
+
+/// Error returned when a data file cannot be read or is not in the expected format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataError {
+    /// The file could not be read.
+    Io { path: PathBuf, kind: io::ErrorKind },
+    /// A line of the file, counting from 1, is not in the expected format.
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for DataError {
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::data::DataError;
+    ///
+    /// let error: DataError = DataError::Parse { line: 3, message: String::from("empty row") };
+    ///
+    /// assert_eq!(error.to_string(), "line 3 : empty row");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            DataError::Io { path, kind } => write!(f, "cannot read {} : {}", path.display(), kind),
+            DataError::Parse { line, message } => write!(f, "line {} : {}", line, message),
+        };
+    }
+}
+
+impl Error for DataError {}
+
+/// Returns the path of the data file `name` in [`FILES_DIR`], which does not depend on the
+/// directory the binary is run from.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::data::path;
+///
+/// assert!(path("0022_names.txt").ends_with("files/0022_names.txt"));
+/// assert!(path("0022_names.txt").is_absolute());
+/// ```
+pub fn path(name: &str) -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(FILES_DIR)
+        .join(name);
+}
+
+/// Reads the data file `name` in [`FILES_DIR`].
+///
+/// Files that must be available wherever the binary is copied to can be embedded instead, with
+/// `include_str!("../files/<name>")` in a module of `src`, and given to the parsers directly.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::data::{read, DataError};
+///
+/// assert!(read("0022_names.txt").unwrap().starts_with("\"MARY\""));
+/// assert!(matches!(read("missing.txt"), Err(DataError::Io { .. })));
+/// ```
+pub fn read(name: &str) -> Result<String, DataError> {
+    let path: PathBuf = path(name);
+
+    return fs::read_to_string(&path).map_err(|error: io::Error| DataError::Io {
+        path,
+        kind: error.kind(),
+    });
+}
+
+/// Reads the data file `name` in [`FILES_DIR`] and parses it with `parse`, which is one of the
+/// `parse_*` functions of this module.
+///
+/// ### Panics
+///
+/// When the file cannot be read or parsed, with the name of the file and the [`DataError`].
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::data::{load, parse_triangle};
+///
+/// let triangle: Vec<Vec<u128>> = load("0018_triangle.txt", parse_triangle);
+///
+/// assert_eq!(triangle.len(), 15);
+/// assert_eq!(triangle[1], vec![95, 64]);
+/// ```
+pub fn load<T>(name: &str, parse: fn(&str) -> Result<T, DataError>) -> T {
+    return read(name)
+        .and_then(|content: String| parse(&content))
+        .unwrap_or_else(|error: DataError| panic!("{} : {}", name, error));
+}
+
+/// Parses a single line of comma separated words in double quotes, like `"MARY","PATRICIA"`.
+///
+/// ### Returns
+///
+/// * `Result<Vec<String>, DataError>` - The words without their quotes, in the order of the file.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::data::parse_words as f;
+///
+/// assert_eq!(f("\"MARY\",\"PATRICIA\"\n"), Ok(vec![String::from("MARY"), String::from("PATRICIA")]));
+/// assert_eq!(f(""), Ok(vec![]));
+///
+/// assert!(f("\"MARY\",PATRICIA").is_err());
+/// assert!(f("\"MARY\",,\"LINDA\"").is_err());
+/// ```
+pub fn parse_words(content: &str) -> Result<Vec<String>, DataError> {
+    let content: &str = content.trim();
+
+    if content.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    return content
+        .split(',')
+        .map(|word: &str| {
+            let word: &str = word.trim();
+
+            if word.len() < 2 || !word.starts_with('"') || !word.ends_with('"') {
+                return Err(DataError::Parse {
+                    line: 1,
+                    message: format!("`{}` is not a quoted word", word),
+                });
+            }
+
+            return Ok(word[1..word.len() - 1].to_string());
+        })
+        .collect();
+}
+
+/// Parses the whitespace separated numbers of a line.
+fn parse_row(line: &str, index: usize) -> Result<Vec<u128>, DataError> {
+    return line
+        .split_whitespace()
+        .map(|num: &str| {
+            return num.parse::<u128>().map_err(|_| DataError::Parse {
+                line: index + 1,
+                message: format!("`{}` is not a number", num),
+            });
+        })
+        .collect();
+}
+
+/// Returns the non-empty lines of `content`, with their index counting from 0.
+fn lines(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    return content
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+}
+
+/// Parses a grid of whitespace separated numbers, one row per line, where every row has the
+/// same length. Leading zeros are allowed, like in `08 02 22`.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::data::parse_grid as f;
+///
+/// assert_eq!(f("08 02\n22 97\n"), Ok(vec![vec![8, 2], vec![22, 97]]));
+///
+/// assert!(f("08 02\n22\n").is_err());
+/// assert!(f("08 xx\n").is_err());
+/// ```
+pub fn parse_grid(content: &str) -> Result<Vec<Vec<u128>>, DataError> {
+    let mut grid: Vec<Vec<u128>> = Vec::new();
+
+    for (index, line) in lines(content) {
+        let row: Vec<u128> = parse_row(line, index)?;
+
+        if grid
+            .first()
+            .is_some_and(|first: &Vec<u128>| first.len() != row.len())
+        {
+            return Err(DataError::Parse {
+                line: index + 1,
+                message: format!("expected {} numbers, found {}", grid[0].len(), row.len()),
+            });
+        }
+
+        grid.push(row);
+    }
+
+    return Ok(grid);
+}
+
+/// Parses a triangle of whitespace separated numbers, where the nth line has n numbers.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::data::parse_triangle as f;
+///
+/// assert_eq!(f("3\n7 4\n2 4 6\n"), Ok(vec![vec![3], vec![7, 4], vec![2, 4, 6]]));
+///
+/// assert!(f("3\n7 4 1\n").is_err());
+/// ```
+pub fn parse_triangle(content: &str) -> Result<Vec<Vec<u128>>, DataError> {
+    let mut triangle: Vec<Vec<u128>> = Vec::new();
+
+    for (index, line) in lines(content) {
+        let row: Vec<u128> = parse_row(line, index)?;
+
+        if row.len() != triangle.len() + 1 {
+            return Err(DataError::Parse {
+                line: index + 1,
+                message: format!(
+                    "expected {} numbers, found {}",
+                    triangle.len() + 1,
+                    row.len()
+                ),
+            });
+        }
+
+        triangle.push(row);
+    }
+
+    return Ok(triangle);
+}
+
+/// Parses a list of numbers, one per line. The numbers are returned as strings of digits, as they
+/// can be too large for any integer type.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::data::parse_number_lines as f;
+///
+/// assert_eq!(
+///     f("37107287533902102798797998220837590246510135740250\n46\n"),
+///     Ok(vec![
+///         String::from("37107287533902102798797998220837590246510135740250"),
+///         String::from("46"),
+///     ])
+/// );
+///
+/// assert!(f("371\n4 6\n").is_err());
+/// ```
+pub fn parse_number_lines(content: &str) -> Result<Vec<String>, DataError> {
+    return lines(content)
+        .map(|(index, line)| {
+            if !line.chars().all(|c: char| c.is_ascii_digit()) {
+                return Err(DataError::Parse {
+                    line: index + 1,
+                    message: format!("`{}` is not a number", line),
+                });
+            }
+
+            return Ok(line.to_string());
+        })
+        .collect();
+}
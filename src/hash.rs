@@ -0,0 +1,314 @@
+// Self contained implementations of MD5 (RFC 1321) and SHA-256 (FIPS 180-4).
+//
+// They are only used to compare answers with stored digests, and are not meant to be fast.
+
+/// Per-round shift amounts of MD5.
+const MD5_SHIFTS: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9,
+    14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10, 15,
+    21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+/// Per-round constants of MD5, `floor(abs(sin(i + 1)) * 2^32)`.
+const MD5_CONSTANTS: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// Round constants of SHA-256, the first 32 bits of the fractional parts of the cube roots of the
+/// first 64 primes.
+const SHA256_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Initial hash values of SHA-256, the first 32 bits of the fractional parts of the square roots
+/// of the first 8 primes.
+const SHA256_INITIAL: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+#[allow(dead_code)]
+/// Pads `data` to a multiple of 64 bytes as done by both MD5 and SHA-256.
+///
+/// A single `1` bit is appended, followed by `0` bits and finally the length of `data` in bits as
+/// a 64 bit integer, little endian for MD5 and big endian for SHA-256.
+fn pad(data: &[u8], big_endian: bool) -> Vec<u8> {
+    let bit_length: u64 = (data.len() as u64).wrapping_mul(8);
+
+    let mut padded: Vec<u8> = data.to_vec();
+    padded.push(0x80);
+
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    }
+
+    match big_endian {
+        true => padded.extend_from_slice(&bit_length.to_be_bytes()),
+        false => padded.extend_from_slice(&bit_length.to_le_bytes()),
+    }
+
+    return padded;
+}
+
+#[allow(dead_code)]
+/// Returns the MD5 digest of `data`.
+///
+/// ### Arguments
+///
+/// * `data` : `&[u8]` - The bytes to hash.
+///
+/// ### Returns
+///
+/// * `[u8; 16]` - The 128 bit digest.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::hash::{md5, to_hex};
+///
+/// assert_eq!(to_hex(&md5(b"")), "d41d8cd98f00b204e9800998ecf8427e");
+/// assert_eq!(to_hex(&md5(b"abc")), "900150983cd24fb0d6963f7d28e17f72");
+/// assert_eq!(
+///     to_hex(&md5(b"The quick brown fox jumps over the lazy dog")),
+///     "9e107d9d372bb6826bd81d3542a419d6"
+/// );
+/// ```
+pub fn md5(data: &[u8]) -> [u8; 16] {
+    let mut state: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+    for block in pad(data, false).chunks(64) {
+        let words: Vec<u32> = block
+            .chunks(4)
+            .map(|word: &[u8]| u32::from_le_bytes([word[0], word[1], word[2], word[3]]))
+            .collect();
+
+        let [mut a, mut b, mut c, mut d] = state;
+
+        for i in 0..64 {
+            let (f, g) = match i / 16 {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+
+            let f: u32 = f
+                .wrapping_add(a)
+                .wrapping_add(MD5_CONSTANTS[i])
+                .wrapping_add(words[g]);
+
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(f.rotate_left(MD5_SHIFTS[i]));
+        }
+
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+    }
+
+    let mut digest: [u8; 16] = [0; 16];
+    for (i, word) in state.iter().enumerate() {
+        digest[4 * i..4 * i + 4].copy_from_slice(&word.to_le_bytes());
+    }
+
+    return digest;
+}
+
+#[allow(dead_code)]
+/// Returns the SHA-256 digest of `data`.
+///
+/// ### Arguments
+///
+/// * `data` : `&[u8]` - The bytes to hash.
+///
+/// ### Returns
+///
+/// * `[u8; 32]` - The 256 bit digest.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::hash::{sha256, to_hex};
+///
+/// assert_eq!(
+///     to_hex(&sha256(b"")),
+///     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+/// );
+/// assert_eq!(
+///     to_hex(&sha256(b"abc")),
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+/// ```
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut state: [u32; 8] = SHA256_INITIAL;
+
+    for block in pad(data, true).chunks(64) {
+        let mut schedule: [u32; 64] = [0; 64];
+
+        for (i, word) in block.chunks(4).enumerate() {
+            schedule[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+
+        for i in 16..64 {
+            let s0: u32 = schedule[i - 15].rotate_right(7)
+                ^ schedule[i - 15].rotate_right(18)
+                ^ (schedule[i - 15] >> 3);
+            let s1: u32 = schedule[i - 2].rotate_right(17)
+                ^ schedule[i - 2].rotate_right(19)
+                ^ (schedule[i - 2] >> 10);
+
+            schedule[i] = schedule[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(schedule[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+
+        for i in 0..64 {
+            let s1: u32 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let choice: u32 = (e & f) ^ (!e & g);
+            let temp1: u32 = h
+                .wrapping_add(s1)
+                .wrapping_add(choice)
+                .wrapping_add(SHA256_CONSTANTS[i])
+                .wrapping_add(schedule[i]);
+
+            let s0: u32 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let majority: u32 = (a & b) ^ (a & c) ^ (b & c);
+            let temp2: u32 = s0.wrapping_add(majority);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+
+        for (word, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *word = word.wrapping_add(value);
+        }
+    }
+
+    let mut digest: [u8; 32] = [0; 32];
+    for (i, word) in state.iter().enumerate() {
+        digest[4 * i..4 * i + 4].copy_from_slice(&word.to_be_bytes());
+    }
+
+    return digest;
+}
+
+#[allow(dead_code)]
+/// Returns the lowercase hexadecimal representation of `bytes`.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::hash::to_hex as f;
+///
+/// assert_eq!(f(&[]), "");
+/// assert_eq!(f(&[0, 15, 16, 255]), "000f10ff");
+/// ```
+pub fn to_hex(bytes: &[u8]) -> String {
+    return bytes
+        .iter()
+        .map(|byte: &u8| format!("{:02x}", byte))
+        .collect();
+}
+
+#[allow(dead_code)]
+/// Parses a hexadecimal string into bytes.
+///
+/// Returns `None` if the string has an odd length or contains a non hexadecimal character.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::hash::from_hex as f;
+///
+/// assert_eq!(f(""), Some(vec![]));
+/// assert_eq!(f("000f10ff"), Some(vec![0, 15, 16, 255]));
+/// assert_eq!(f("000F10FF"), Some(vec![0, 15, 16, 255]));
+/// assert_eq!(f("abc"), None);
+/// assert_eq!(f("zz"), None);
+/// ```
+pub fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 || !hex.is_ascii() {
+        return None;
+    }
+
+    return (0..hex.len())
+        .step_by(2)
+        .map(|i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    // test vectors crossing the block boundaries.
+    use super::*;
+
+    #[test]
+    fn test_md5() {
+        let test_cases: Vec<(&[u8], &str)> = vec![
+            (b"a", "0cc175b9c0f1b6a831c399e269772661"),
+            (b"message digest", "f96b697d7cb7938d525a2f31aaf161d0"),
+            (
+                b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+                "57edf4a22be3c955ac49da2e2107b67a",
+            ),
+            (&[b'a'; 55], "ef1772b6dff9a122358552954ad0df65"),
+            (&[b'a'; 56], "3b0c8ac703f828b04c6c197006d17218"),
+            (&[b'a'; 64], "014842d480b571495a4a0363793f7367"),
+        ];
+
+        for (input, expected_output) in test_cases {
+            assert_eq!(to_hex(&md5(input)), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_sha256() {
+        let test_cases: Vec<(&[u8], &str)> = vec![
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+            ),
+            (
+                &[b'a'; 55],
+                "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318",
+            ),
+            (
+                &[b'a'; 56],
+                "b35439a4ac6f0948b6d6f9e3c6af0f5f590ce20f1bde7090ef7970686ec6738a",
+            ),
+            (
+                &[b'a'; 64],
+                "ffe054fe7ae0cb6dc65c3af9b61d5209f439851db43d0ba5997337df154668eb",
+            ),
+        ];
+
+        for (input, expected_output) in test_cases {
+            assert_eq!(to_hex(&sha256(input)), expected_output);
+        }
+    }
+}
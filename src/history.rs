@@ -0,0 +1,342 @@
+use crate::problem::Params;
+use crate::report::{self, Record, Status};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// History file, relative to the root of the crate.
+pub const HISTORY_FILE: &str = "target/euler-history.jsonl";
+
+/// Percentage by which a solution must get slower to be flagged as a regression by default.
+pub const DEFAULT_THRESHOLD: u32 = 10;
+
+/// Returns the path of [`HISTORY_FILE`] in the root of the crate.
+pub fn default_path() -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR")).join(HISTORY_FILE);
+}
+
+/// Timing of a solution read back from the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Seconds since the Unix epoch of the run the entry was saved by.
+    pub timestamp: u64,
+    pub problem: u32,
+    pub solution: String,
+    /// Values of the parameters the solution was run with, in the order they were declared.
+    pub params: Vec<(String, u128)>,
+    pub elapsed: Duration,
+}
+
+impl Entry {
+    /// Returns whether the entry was saved by a run with the values `params`.
+    pub fn has_params(&self, params: &Params) -> bool {
+        return self
+            .params
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+            .eq(params
+                .values()
+                .iter()
+                .map(|&(name, value): &(&str, u128)| (name, value)));
+    }
+}
+
+/// Returns the lines appended to the history file for `records`, one JSON object per record
+/// with a `timestamp` field followed by the fields of [`report::to_json_object`] and a `params`
+/// object with the values of the parameters. `elapsed_ns` is the median of the timed runs.
+///
+/// Records of solutions that did not return an answer are left out, as their time says nothing
+/// about the solution.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::answer::Answer;
+/// use project_euler::history::to_history_lines;
+/// use project_euler::problems;
+/// use project_euler::report::{Record, Status};
+/// use std::time::Duration;
+///
+/// let record: Record = Record {
+///     problem: 3,
+///     solution: "solution_1",
+///     params: problems::get(3).unwrap().params(&[]),
+///     answer: Answer::Integer(6857),
+///     elapsed: Duration::from_nanos(2889400),
+///     status: Status::Solved,
+///     measurement: None,
+///     allocations: None,
+/// };
+///
+/// assert_eq!(
+///     to_history_lines(&[record], 1700000000),
+///     "{\"timestamp\":1700000000,\"problem\":3,\"solution\":\"solution_1\",\"answer\":\"6857\",\"elapsed_ns\":2889400,\"status\":\"SOLVED\",\"params\":{\"number\":600851475143}}\n"
+/// );
+/// ```
+pub fn to_history_lines(records: &[Record], timestamp: u64) -> String {
+    let mut lines: String = String::new();
+
+    for record in records
+        .iter()
+        .filter(|record| record.status == Status::Solved)
+    {
+        let object: String = report::to_json_object(record);
+        let params: Vec<String> = record
+            .params
+            .values()
+            .iter()
+            .map(|(name, value)| format!("\"{}\":{}", name, value))
+            .collect();
+
+        lines.push_str(&format!(
+            "{{\"timestamp\":{},{},\"params\":{{{}}}}}\n",
+            timestamp,
+            &object[1..object.len() - 1],
+            params.join(",")
+        ));
+    }
+
+    return lines;
+}
+
+/// Appends the timings of `records` to the history file at `path`, creating it if needed.
+pub fn append(path: &Path, records: &[Record]) -> Result<(), String> {
+    let timestamp: u64 = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed: Duration| elapsed.as_secs());
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("cannot create {} : {}", parent.display(), error))?;
+    }
+
+    let mut file: fs::File = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|error| format!("cannot open {} : {}", path.display(), error))?;
+
+    return file
+        .write_all(to_history_lines(records, timestamp).as_bytes())
+        .map_err(|error| format!("cannot write {} : {}", path.display(), error));
+}
+
+/// Returns the value of the number field `key` of a flat JSON object.
+fn number_field(object: &str, key: &str) -> Option<u64> {
+    let start: usize = object.find(&format!("\"{}\":", key))? + key.len() + 3;
+    let digits: String = object[start..]
+        .chars()
+        .take_while(|c: &char| c.is_ascii_digit())
+        .collect();
+
+    return digits.parse::<u64>().ok();
+}
+
+/// Returns the value of the string field `key` of a flat JSON object.
+fn string_field(object: &str, key: &str) -> Option<String> {
+    let start: usize = object.find(&format!("\"{}\":\"", key))? + key.len() + 4;
+
+    let mut value: String = String::new();
+    let mut chars = object[start..].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Some(value),
+            '\\' => value.push(chars.next()?),
+            c => value.push(c),
+        }
+    }
+
+    return None;
+}
+
+/// Returns the names and values of the `params` object of a history entry, which is empty for
+/// entries saved before the parameters were recorded.
+fn params_field(object: &str) -> Option<Vec<(String, u128)>> {
+    let start: usize = match object.find("\"params\":{") {
+        Some(start) => start + "\"params\":{".len(),
+        None => return Some(Vec::new()),
+    };
+    let end: usize = start + object[start..].find('}')?;
+
+    return object[start..end]
+        .split(',')
+        .filter(|param: &&str| !param.is_empty())
+        .map(|param: &str| {
+            let (name, value) = param.split_once(':')?;
+            let name: &str = name.strip_prefix('"')?.strip_suffix('"')?;
+
+            return Some((name.to_string(), value.parse::<u128>().ok()?));
+        })
+        .collect();
+}
+
+/// Parses the contents of a history file.
+///
+/// ### Returns
+///
+/// * `Result<Vec<Entry>, String>` - The entries in the order they were saved, or a message
+///   describing the first invalid line.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::history::parse_history as f;
+/// use std::time::Duration;
+///
+/// let entries = f("\
+/// {\"timestamp\":1,\"problem\":3,\"solution\":\"solution_1\",\"answer\":\"6857\",\"elapsed_ns\":2000}
+///
+/// {\"timestamp\":2,\"problem\":3,\"solution\":\"solution_2\",\"answer\":\"6857\",\"elapsed_ns\":5000,\"params\":{\"number\":13195}}
+/// ").unwrap();
+///
+/// assert_eq!(entries.len(), 2);
+/// assert_eq!(entries[1].timestamp, 2);
+/// assert_eq!(entries[1].solution, "solution_2");
+/// assert_eq!(entries[1].elapsed, Duration::from_nanos(5000));
+/// assert_eq!(entries[1].params, vec![(String::from("number"), 13195)]);
+/// assert!(entries[0].params.is_empty());
+///
+/// assert!(f("{\"timestamp\":1,\"problem\":3}").is_err());
+/// assert!(f("{\"timestamp\":1,\"problem\":3,\"solution\":\"solution_1\",\"elapsed_ns\":1,\"params\":{\"number\":x}}").is_err());
+/// ```
+pub fn parse_history(content: &str) -> Result<Vec<Entry>, String> {
+    let mut entries: Vec<Entry> = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line: &str = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let entry: Option<Entry> = (|| {
+            return Some(Entry {
+                timestamp: number_field(line, "timestamp")?,
+                problem: number_field(line, "problem")? as u32,
+                solution: string_field(line, "solution")?,
+                params: params_field(line)?,
+                elapsed: Duration::from_nanos(number_field(line, "elapsed_ns")?),
+            });
+        })();
+
+        match entry {
+            Some(entry) => entries.push(entry),
+            None => return Err(format!("line {} : invalid history entry", index + 1)),
+        }
+    }
+
+    return Ok(entries);
+}
+
+/// Reads the history file at `path`.
+pub fn load(path: &Path) -> Result<Vec<Entry>, String> {
+    let content: String = fs::read_to_string(path)
+        .map_err(|error| format!("cannot read {} : {}", path.display(), error))?;
+
+    return parse_history(&content);
+}
+
+/// Change in the time of a solution compared to the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub problem: u32,
+    pub solution: &'static str,
+    /// Time of the latest baseline entry of the solution, if there is one.
+    pub baseline: Option<Duration>,
+    pub current: Duration,
+    /// Change in percent, positive when the solution got slower.
+    pub change: Option<f64>,
+    /// Whether the solution got slower by more than the threshold.
+    pub is_regression: bool,
+}
+
+/// Compares the time of every solved record with the latest entry of the same solution, run with
+/// the same parameters, in `baseline`.
+///
+/// ### Arguments
+///
+/// * `records` : `&[Record]` - The records of the current run.
+/// * `baseline` : `&[Entry]` - The saved entries, in the order they were saved.
+/// * `threshold` : `u32` - Percentage by which a solution must get slower to be a regression.
+///
+/// ### Returns
+///
+/// * `Vec<Comparison>` - One comparison per solved record, in the order of `records`.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::answer::Answer;
+/// use project_euler::history::{compare, parse_history};
+/// use project_euler::problems;
+/// use project_euler::report::{Record, Status};
+/// use std::time::Duration;
+///
+/// let baseline = parse_history("\
+/// {\"timestamp\":1,\"problem\":14,\"solution\":\"solution_1\",\"elapsed_ns\":1000,\"params\":{\"limit\":1000000}}
+/// {\"timestamp\":2,\"problem\":14,\"solution\":\"solution_1\",\"elapsed_ns\":2000,\"params\":{\"limit\":1000000}}
+/// {\"timestamp\":3,\"problem\":14,\"solution\":\"solution_1\",\"elapsed_ns\":10,\"params\":{\"limit\":10}}
+/// ").unwrap();
+///
+/// let record = |solution: &'static str, limit: u128, elapsed_ns: u64| -> Record {
+///     return Record {
+///         problem: 14,
+///         solution,
+///         params: problems::get(14).unwrap().params(&[(String::from("limit"), limit)]),
+///         answer: Answer::Integer(837799),
+///         elapsed: Duration::from_nanos(elapsed_ns),
+///         status: Status::Solved,
+///         measurement: None,
+///         allocations: None,
+///     };
+/// };
+///
+/// let comparisons = compare(
+///     &[record("solution_1", 1000000, 2100), record("solution_2", 1000000, 10)],
+///     &baseline,
+///     10,
+/// );
+/// assert_eq!(comparisons[0].baseline, Some(Duration::from_nanos(2000)));
+/// assert!((comparisons[0].change.unwrap() - 5.0).abs() < 1e-9);
+/// assert!(!comparisons[0].is_regression);
+/// assert_eq!(comparisons[1].baseline, None);
+///
+/// let comparisons = compare(&[record("solution_1", 1000000, 2500)], &baseline, 10);
+/// assert!(comparisons[0].is_regression);
+///
+/// let comparisons = compare(&[record("solution_1", 100, 2500)], &baseline, 10);
+/// assert_eq!(comparisons[0].baseline, None);
+/// ```
+pub fn compare(records: &[Record], baseline: &[Entry], threshold: u32) -> Vec<Comparison> {
+    return records
+        .iter()
+        .filter(|record: &&Record| record.status == Status::Solved)
+        .map(|record: &Record| {
+            let baseline: Option<Duration> = baseline
+                .iter()
+                .rev()
+                .find(|entry: &&Entry| {
+                    return entry.problem == record.problem
+                        && entry.solution == record.solution
+                        && entry.has_params(&record.params);
+                })
+                .map(|entry: &Entry| entry.elapsed);
+
+            let change: Option<f64> = baseline
+                .filter(|baseline: &Duration| !baseline.is_zero())
+                .map(|baseline: Duration| {
+                    return (record.elapsed.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0;
+                });
+
+            return Comparison {
+                problem: record.problem,
+                solution: record.solution,
+                baseline,
+                current: record.elapsed,
+                change,
+                is_regression: change.is_some_and(|change: f64| change > threshold as f64),
+            };
+        })
+        .collect();
+}
@@ -0,0 +1,330 @@
+use crate::allocation::AllocationStats;
+use crate::answer::Answer;
+use crate::problem::{Params, Problem, Solution};
+use crate::report::{Record, Status};
+use crate::timing::{Measurement, Sampling};
+use std::env;
+use std::io::Read;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Time a solution may run for, from Project Euler's one-minute rule.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How often a running child process is checked for completion.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Message printed by the standard library when an allocation fails.
+const ALLOCATION_FAILURE: &str = "memory allocation of";
+
+/// Limits applied to every solution run in a child process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Wall-clock time after which the child process is killed.
+    pub timeout: Duration,
+    /// Maximum size of the address space of the child process in bytes, if any.
+    ///
+    /// The limit is set with `setrlimit(RLIMIT_AS)` and is ignored on platforms other than Unix.
+    pub memory: Option<u64>,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        return Limits {
+            timeout: DEFAULT_TIMEOUT,
+            memory: None,
+        };
+    }
+}
+
+/// Runs the solutions called `variants`, or every solution when `variants` is empty, each in its
+/// own child process, like [`Problem::run`] does in the current process.
+///
+/// The child process is the current executable, which must be the `project_euler` binary, run
+/// with the solution and parameters on its command line. Answers read back from the child are
+/// [`Answer::Text`], which display the same as the answers they were printed from.
+///
+/// ### Arguments
+///
+/// * `problem` : `&dyn Problem` - The problem to run.
+/// * `params` : `&Params` - Values of the parameters, passed to the child with `--param`.
+/// * `variants` : `&[String]` - Names of the solutions to run, or empty for all of them.
+/// * `sampling` : `&Sampling` - Number of untimed and timed runs of every solution, all made in
+///   the same child process and within the same timeout.
+/// * `limits` : `&Limits` - Timeout and memory cap of every child process.
+///
+/// ### Returns
+///
+/// * `Vec<Record>` - One record per solution run, with a [`Status`] other than
+///   [`Status::Solved`] for solutions that timed out, ran out of memory or crashed.
+///
+/// ### Panics
+///
+/// When the child process cannot be started.
+pub fn run(
+    problem: &dyn Problem,
+    params: &Params,
+    variants: &[String],
+    sampling: &Sampling,
+    limits: &Limits,
+) -> Vec<Record> {
+    return problem
+        .selected_solutions(variants)
+        .iter()
+        .map(|solution: &Solution| {
+            return run_solution(problem.number(), solution.name, params, sampling, limits);
+        })
+        .collect();
+}
+
+/// Runs a single solution in a child process. See [`run`].
+pub fn run_solution(
+    problem: u32,
+    solution: &'static str,
+    params: &Params,
+    sampling: &Sampling,
+    limits: &Limits,
+) -> Record {
+    let executable = env::current_exe().unwrap();
+
+    let mut command: Command = Command::new(executable);
+    command.arg(format!("{}:{}", problem, solution));
+    command.args(["--format", "csv"]);
+    command.arg("--warm-up").arg(sampling.warm_up.to_string());
+    command.arg("--repeats").arg(sampling.repeats.to_string());
+    for (name, value) in params.values() {
+        command.arg("--param").arg(format!("{}={}", name, value));
+    }
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    if let Some(memory) = limits.memory {
+        limit_memory(&mut command, memory);
+    }
+
+    let start_time: Instant = Instant::now();
+    let mut child: Child = command.spawn().unwrap();
+    let stdout: JoinHandle<String> = drain(child.stdout.take());
+    let stderr: JoinHandle<String> = drain(child.stderr.take());
+    let exit_status: Option<ExitStatus> = wait_with_timeout(&mut child, limits.timeout);
+    let elapsed_time: Duration = start_time.elapsed();
+    let stdout: String = stdout.join().unwrap_or_default();
+    let stderr: String = stderr.join().unwrap_or_default();
+
+    let mut record: Record = Record {
+        problem,
+        solution,
+        params: params.clone(),
+        answer: Answer::Text(String::new()),
+        elapsed: elapsed_time,
+        status: Status::Timeout,
+        measurement: None,
+        allocations: None,
+    };
+
+    let exit_status: ExitStatus = match exit_status {
+        Some(exit_status) => exit_status,
+        None => return record,
+    };
+
+    record.status = match parse_csv_output(&stdout) {
+        Some((answer, elapsed, samples, allocations)) if exit_status.success() => {
+            record.answer = Answer::Text(answer);
+            record.elapsed = elapsed;
+            if !samples.is_empty() {
+                record.measurement = Some(Measurement::new(solution, samples));
+            }
+            record.allocations = allocations;
+            Status::Solved
+        }
+        _ if stderr.contains(ALLOCATION_FAILURE) => Status::OutOfMemory,
+        _ => Status::Crashed,
+    };
+
+    return record;
+}
+
+/// Reads `pipe` to the end on a new thread.
+///
+/// The pipes of a child process must be read while waiting for it, as a child writing more than
+/// the pipe buffer, like a long panic message, blocks until the pipe is read.
+fn drain(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<String> {
+    return thread::spawn(move || {
+        let mut output: String = String::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_string(&mut output);
+        }
+
+        return output;
+    });
+}
+
+/// Waits for `child` to exit, killing it once `timeout` has passed.
+///
+/// Returns `None` if the child was killed.
+fn wait_with_timeout(child: &mut Child, timeout: Duration) -> Option<ExitStatus> {
+    let start_time: Instant = Instant::now();
+
+    loop {
+        if let Ok(Some(exit_status)) = child.try_wait() {
+            return Some(exit_status);
+        }
+
+        if start_time.elapsed() >= timeout {
+            let _ = child.kill();
+            child.wait().unwrap();
+            return None;
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(unix)]
+/// Caps the address space of the process started by `command` to `bytes`.
+fn limit_memory(command: &mut Command, bytes: u64) {
+    use std::io;
+    use std::os::unix::process::CommandExt;
+
+    let limit: libc::rlimit = libc::rlimit {
+        rlim_cur: bytes as libc::rlim_t,
+        rlim_max: bytes as libc::rlim_t,
+    };
+
+    // SAFETY: `setrlimit` is async-signal-safe and only changes the limits of the child process.
+    unsafe {
+        command.pre_exec(move || {
+            if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            return Ok(());
+        });
+    }
+}
+
+#[cfg(not(unix))]
+/// Memory caps are only supported on Unix.
+fn limit_memory(_command: &mut Command, _bytes: u64) {}
+
+/// Answer, elapsed time, time of every timed run and allocations of a solution run in a child
+/// process.
+type ChildOutput = (String, Duration, Vec<Duration>, Option<AllocationStats>);
+
+/// Reads the answer, elapsed time, timed runs and allocations of the first record of the output
+/// of `--format csv`.
+///
+/// The answer is the only field that can contain commas, in which case it is quoted.
+fn parse_csv_output(output: &str) -> Option<ChildOutput> {
+    let line: &str = output.lines().nth(1)?;
+
+    let mut fields = line.rsplitn(10, ',');
+    let samples: &str = fields.next()?;
+    let _stddev_ns: &str = fields.next()?;
+    let _mean_ns: &str = fields.next()?;
+    let _min_ns: &str = fields.next()?;
+    let peak_bytes: Option<usize> = fields.next()?.parse::<usize>().ok();
+    let allocated_bytes: Option<usize> = fields.next()?.parse::<usize>().ok();
+    let allocations: Option<usize> = fields.next()?.parse::<usize>().ok();
+    let _status: &str = fields.next()?;
+    let elapsed_ns: u64 = fields.next()?.parse::<u64>().ok()?;
+    let rest: &str = fields.next()?;
+
+    let samples: Vec<Duration> = samples
+        .split(';')
+        .filter(|sample: &&str| !sample.is_empty())
+        .map(|sample: &str| sample.parse::<u64>().ok().map(Duration::from_nanos))
+        .collect::<Option<Vec<Duration>>>()?;
+
+    let allocations: Option<AllocationStats> = match (allocations, allocated_bytes, peak_bytes) {
+        (Some(allocations), Some(allocated_bytes), Some(peak_bytes)) => Some(AllocationStats {
+            allocations,
+            allocated_bytes,
+            peak_bytes,
+        }),
+        _ => None,
+    };
+
+    let mut fields = rest.splitn(3, ',');
+    let _problem: &str = fields.next()?;
+    let _solution: &str = fields.next()?;
+    let answer: &str = fields.next()?;
+
+    let answer: String = match answer.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(quoted) => quoted.replace("\"\"", "\""),
+        None => answer.to_string(),
+    };
+
+    return Some((
+        answer,
+        Duration::from_nanos(elapsed_ns),
+        samples,
+        allocations,
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_output() {
+        let header: &str = "problem,solution,answer,elapsed_ns,status,allocations,allocated_bytes,peak_bytes,min_ns,mean_ns,stddev_ns,samples_ns\n";
+
+        let test_cases: Vec<(String, Option<ChildOutput>)> = vec![
+            (
+                format!("{}3,solution_1,6857,2889400,SOLVED,,,,,,,\n", header),
+                Some((
+                    String::from("6857"),
+                    Duration::from_nanos(2889400),
+                    vec![],
+                    None,
+                )),
+            ),
+            (
+                format!(
+                    "{}3,solution_1,6857,2000,SOLVED,,,,1000,2000,1000,1000;2000;3000\n",
+                    header
+                ),
+                Some((
+                    String::from("6857"),
+                    Duration::from_nanos(2000),
+                    vec![
+                        Duration::from_nanos(1000),
+                        Duration::from_nanos(2000),
+                        Duration::from_nanos(3000),
+                    ],
+                    None,
+                )),
+            ),
+            (
+                format!(
+                    "{}27,solution_1,\"a : -61, b : 971\",500,SOLVED,2,96,64,500,500,0,500\n",
+                    header
+                ),
+                Some((
+                    String::from("a : -61, b : 971"),
+                    Duration::from_nanos(500),
+                    vec![Duration::from_nanos(500)],
+                    Some(AllocationStats {
+                        allocations: 2,
+                        allocated_bytes: 96,
+                        peak_bytes: 64,
+                    }),
+                )),
+            ),
+            (
+                format!("{}3,solution_1,6857,2000,SOLVED,,,,,,,1000;x\n", header),
+                None,
+            ),
+            (String::from(header), None),
+            (String::new(), None),
+        ];
+
+        for (input, expected_output) in test_cases {
+            assert_eq!(parse_csv_output(&input), expected_output);
+        }
+    }
+}
@@ -1,4 +1,17 @@
+pub mod allocation;
+pub mod answer;
+pub mod cli;
+pub mod complexity;
+pub mod data;
+pub mod hash;
+pub mod history;
+pub mod isolate;
 pub mod macros;
 pub mod maths;
 
+pub mod problem;
 pub mod problems;
+pub mod report;
+pub mod scaffold;
+pub mod timing;
+pub mod verify;
@@ -1,9 +1,20 @@
 /// Used to time one or more functions.
 ///
+/// Every function is run `warm_up` times without timing it, then timed over `repeats` runs. Both
+/// counts are optional and default to [`DEFAULT_WARM_UP`](crate::timing::DEFAULT_WARM_UP) and
+/// [`DEFAULT_REPEATS`](crate::timing::DEFAULT_REPEATS).
+///
+/// The statistics of every function are printed and returned as a
+/// `Vec<`[`Measurement`](crate::timing::Measurement)`>`, in the order the functions were given.
+///
+/// Starting the arguments with `check` also compares the results of all the functions, which
+/// must then be of types implementing `Debug`, and panics if they disagree.
+///
 /// ### Examples
 ///
 /// ```
 /// use project_euler::time_solutions;
+/// use project_euler::timing::Measurement;
 ///
 /// fn with_parameters(n1: u128, n2: u64) -> u128 {
 ///     return n1 + n2 as u128;
@@ -13,35 +24,113 @@
 ///     return 248724874;
 /// }
 ///
-/// time_solutions!(
+/// let measurements: Vec<Measurement> = time_solutions!(
 ///     with_parameters(45, 45454),
 ///     without_parameters()
 /// );
 ///
+/// assert_eq!(measurements.len(), 2);
+/// assert_eq!(measurements[0].name, "with_parameters");
+/// assert_eq!(measurements[0].samples.len(), 10);
+///
+/// let measurements: Vec<Measurement> = time_solutions!(
+///     warm_up = 3, repeats = 50;
+///     with_parameters(45, 45454),
+/// );
+///
+/// assert_eq!(measurements[0].samples.len(), 50);
+/// assert!(measurements[0].min <= measurements[0].median);
+///
+/// time_solutions!(check; with_parameters(45, 45454), with_parameters(45454, 45));
+/// time_solutions!(check, warm_up = 0, repeats = 1; with_parameters(1, 1));
+/// ```
+///
+/// ```should_panic
+/// use project_euler::time_solutions;
+///
+/// fn solution_1() -> u128 {
+///     return 6857;
+/// }
+///
+/// fn solution_2() -> u128 {
+///     return 1471;
+/// }
+///
+/// time_solutions!(check; solution_1(), solution_2());
 /// ```
 ///
 /// Output:
 ///
 /// ```rust,ignore
 /// -----------------------------------------------------------
-/// with_parameters took 300ns
+/// with_parameters took 30ns (min 20ns, mean 31ns ± 8ns, 10 runs)
 /// -----------------------------------------------------------
-/// without_parameters took 100ns
+/// without_parameters took 20ns (min 20ns, mean 22ns ± 4ns, 10 runs)
 ///
 /// ```
 #[allow(unused_macros)]
 #[macro_export]
 macro_rules! time_solutions {
-    () => {};
+    (check, warm_up = $warm_up:expr, repeats = $repeats:expr; $($calls:tt)*) => {{
+        let mut measurements: Vec<$crate::timing::Measurement> = Vec::new();
+        let mut results: Vec<(&str, String)> = Vec::new();
+        $crate::time_solutions!(
+            @measure check, measurements, results, $warm_up, $repeats; $($calls)*
+        );
+        $crate::timing::assert_agreement(&results);
+        measurements
+    }};
+
+    (check; $($calls:tt)*) => {
+        $crate::time_solutions!(
+            check,
+            warm_up = $crate::timing::DEFAULT_WARM_UP,
+            repeats = $crate::timing::DEFAULT_REPEATS;
+            $($calls)*
+        )
+    };
+
+    (warm_up = $warm_up:expr, repeats = $repeats:expr; $($calls:tt)*) => {{
+        let mut measurements: Vec<$crate::timing::Measurement> = Vec::new();
+        let results: Vec<(&str, String)> = Vec::new();
+        $crate::time_solutions!(
+            @measure no_check, measurements, results, $warm_up, $repeats; $($calls)*
+        );
+        measurements
+    }};
+
+    (@measure $mode:ident, $measurements:ident, $results:ident, $warm_up:expr, $repeats:expr;) => {};
 
-    ($func:ident ( $($param:expr),* $(,)? ) $(, $($rest:tt)*)* ) => {
+    (@measure $mode:ident, $measurements:ident, $results:ident, $warm_up:expr, $repeats:expr;
+        $func:ident ( $($param:expr),* $(,)? ) $(, $($rest:tt)*)*
+    ) => {
         println!("-----------------------------------------------------------");
         {
-            let start_time = std::time::Instant::now();
-            $func($($param),*);
-            let elapsed_time = start_time.elapsed();
-            println!("{} took {:?}", stringify!($func), elapsed_time);
+            let (result, samples) =
+                $crate::timing::sample($warm_up, $repeats, || $func($($param),*));
+            let measurement = $crate::timing::Measurement::new(stringify!($func), samples);
+            println!("{}", measurement);
+            $measurements.push(measurement);
+            $crate::time_solutions!(@capture $mode, $results, stringify!($func), result);
         }
-        time_solutions!($($($rest)*)*);
+        $crate::time_solutions!(
+            @measure $mode, $measurements, $results, $warm_up, $repeats; $($($rest)*)*
+        );
+    };
+
+    (@capture check, $results:ident, $name:expr, $result:ident) => {
+        $results.push(($name, format!("{:?}", $result)));
+    };
+
+    (@capture no_check, $results:ident, $name:expr, $result:ident) => {
+        let _ = (&$results, &$result);
+    };
+
+    ($($calls:tt)*) => {
+        $crate::time_solutions!(
+            warm_up = $crate::timing::DEFAULT_WARM_UP,
+            repeats = $crate::timing::DEFAULT_REPEATS;
+            $($calls)*
+        )
     };
 }
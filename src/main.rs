@@ -1,62 +1,385 @@
+#[cfg(feature = "count-allocations")]
+use project_euler::allocation::{self, CountingAllocator};
+use project_euler::cli::{self, Command, Options};
+use project_euler::complexity;
+use project_euler::history::{self, Comparison, Entry};
+use project_euler::isolate;
+use project_euler::problem::{Parameter, Params, Problem};
+use project_euler::problems;
+use project_euler::report::{self, Format, Record};
+use project_euler::scaffold;
+use project_euler::verify::{self, Digest, Verdict};
+use std::collections::HashMap;
 use std::env;
-mod macros;
-mod maths;
-mod problems;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    #[cfg(feature = "count-allocations")]
+    allocation::enable();
+
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let options: Options = match cli::parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("error : {}", message);
+            eprintln!("Run with --help for usage.");
+            process::exit(2);
+        }
+    };
+
+    match options.command {
+        Command::Help => print!("{}", cli::USAGE),
+        Command::List => list_problems(),
+        Command::Verify => verify_problems(&options),
+        Command::Run => run_problems(&options),
+        Command::Compare => compare_problems(&options),
+        Command::New => new_problem(&options),
+        Command::Complexity => estimate_complexity(&options),
+    }
+}
 
-    if args.len() < 2 {
-        println!("Invalid Command");
+/// Creates and registers the module of the problem given to `new`.
+fn new_problem(options: &Options) {
+    let number: u32 = options.problems[0];
+
+    match scaffold::create(Path::new(scaffold::CRATE_DIR), number, &options.title) {
+        Ok(module_path) => {
+            println!("Created {}", module_path.display());
+            println!("Registered problem {} in src/problems/mod.rs", number);
+        }
+        Err(message) => {
+            eprintln!("error : {}", message);
+            process::exit(1);
+        }
+    }
+}
+
+fn list_problems() {
+    for problem in problems::PROBLEMS {
+        println!(
+            "{:>4} | {:<36} | {} solution(s)",
+            problem.number(),
+            problem.title(),
+            problem.solutions().len()
+        );
+        for parameter in problem.parameters() {
+            println!(
+                "     |   --param {}={} : {}",
+                parameter.name, parameter.default, parameter.description
+            );
+        }
+    }
+}
+
+fn run_problems(options: &Options) {
+    if options.format != Format::Text {
+        let records: Vec<Record> = options
+            .problems
+            .iter()
+            .filter_map(|&problem_number| problems::get(problem_number))
+            .flat_map(|problem| run_problem(problem, &problem.params(&options.params), options))
+            .collect();
+
+        match options.format {
+            Format::Json => print!("{}", report::to_json(&records)),
+            _ => print!("{}", report::to_csv(&records)),
+        }
+        save_history(options, &records);
+        exit_if_disagreeing(&records);
         return;
     }
 
-    if args[1] == "all" {
-        todo!();
+    let mut records: Vec<Record> = Vec::new();
+
+    for &problem_number in &options.problems {
+        println!("=============================================================================");
+        println!("Problem : {}", problem_number);
+        if let Some(problem) = problems::get(problem_number) {
+            let params: Params = problem.params(&options.params);
+
+            if !options.params.is_empty() && !params.values().is_empty() {
+                let values: Vec<String> = params
+                    .values()
+                    .iter()
+                    .map(|(name, value)| format!("{} = {}", name, value))
+                    .collect();
+                println!("Parameters : {}", values.join(", "));
+            }
+
+            if options.limits.is_some() {
+                let problem_records: Vec<Record> = run_problem(problem, &params, options);
+                report::print_records(&problem_records);
+                records.extend(problem_records);
+            } else {
+                records.extend(problem.solve(
+                    &params,
+                    &options.variants_of(problem_number),
+                    &options.sampling,
+                ));
+            }
+        }
+    }
+    println!("=============================================================================");
+
+    if options.summary {
+        print!("{}", report::summary_table(&records));
+        println!("=============================================================================");
     }
 
-    let problem_numbers: Vec<u32> = args[1..]
+    save_history(options, &records);
+    exit_if_disagreeing(&records);
+}
+
+/// Exits with a non-zero code if the solutions of any problem in `records` returned different
+/// answers, printing which ones.
+fn exit_if_disagreeing(records: &[Record]) {
+    let disagreements: Vec<String> = report::disagreements(records);
+
+    for message in &disagreements {
+        eprintln!("error : {}", message);
+    }
+
+    if !disagreements.is_empty() {
+        process::exit(1);
+    }
+}
+
+/// Appends the times of `records` to the history file if `--save` was given.
+fn save_history(options: &Options, records: &[Record]) {
+    if !options.save {
+        return;
+    }
+
+    if let Err(message) = history::append(&options.history, records) {
+        eprintln!("error : {}", message);
+        process::exit(1);
+    }
+}
+
+/// Runs the selected problems and compares the time of every solution with the latest time
+/// saved in the history file. Exits with a non-zero code if any solution got slower by more
+/// than the threshold.
+fn compare_problems(options: &Options) {
+    let baseline: Vec<Entry> = match history::load(&options.history) {
+        Ok(baseline) => baseline,
+        Err(message) => {
+            eprintln!("error : {}", message);
+            eprintln!("Save a baseline first with --save.");
+            process::exit(2);
+        }
+    };
+
+    let records: Vec<Record> = options
+        .problems
         .iter()
-        .map(|num: &String| num.parse::<u32>().unwrap())
+        .filter_map(|&problem_number| problems::get(problem_number))
+        .flat_map(|problem| run_problem(problem, &problem.params(&options.params), options))
         .collect();
 
-    for problem in problem_numbers {
-        println!("=============================================================================");
-        println!("Problem : {}", problem);
-        match problem {
-            1 => problems::problem_1::solve(),
-            2 => problems::problem_2::solve(),
-            3 => problems::problem_3::solve(),
-            4 => problems::problem_4::solve(),
-            5 => problems::problem_5::solve(),
-            6 => problems::problem_6::solve(),
-            7 => problems::problem_7::solve(),
-            8 => problems::problem_8::solve(),
-            9 => problems::problem_9::solve(),
-            10 => problems::problem_10::solve(),
-            11 => problems::problem_11::solve(),
-            12 => problems::problem_12::solve(),
-            13 => problems::problem_13::solve(),
-            14 => problems::problem_14::solve(),
-            15 => problems::problem_15::solve(),
-            16 => problems::problem_16::solve(),
-            17 => problems::problem_17::solve(),
-            18 => problems::problem_18::solve(),
-            19 => problems::problem_19::solve(),
-            20 => problems::problem_20::solve(),
-            21 => problems::problem_21::solve(),
-            22 => problems::problem_22::solve(),
-            23 => problems::problem_23::solve(),
-            24 => problems::problem_24::solve(),
-            25 => problems::problem_25::solve(),
-            26 => problems::problem_26::solve(),
-            27 => problems::problem_27::solve(),
-            28 => problems::problem_28::solve(),
-            29 => problems::problem_29::solve(),
-            30 => problems::problem_30::solve(),
-            31 => problems::problem_31::solve(),
-            _ => println!("Problem Not Solved"),
+    let comparisons: Vec<Comparison> = history::compare(&records, &baseline, options.threshold);
+
+    for comparison in &comparisons {
+        let change: String = match (comparison.baseline, comparison.change) {
+            (Some(baseline), Some(change)) => {
+                format!(
+                    "{:.2?} -> {:.2?} ({:+.1}%)",
+                    baseline, comparison.current, change
+                )
+            }
+            _ => format!("{:.2?} (no baseline)", comparison.current),
+        };
+        let flag: &str = if comparison.is_regression {
+            " REGRESSION"
+        } else {
+            ""
+        };
+
+        println!(
+            "Problem {} {} : {}{}",
+            comparison.problem, comparison.solution, change, flag
+        );
+    }
+
+    let n_regressions: usize = comparisons
+        .iter()
+        .filter(|comparison| comparison.is_regression)
+        .count();
+    println!(
+        "Regressions : {} of {} solutions (threshold {}%)",
+        n_regressions,
+        comparisons.len(),
+        options.threshold
+    );
+
+    save_history(options, &records);
+
+    if n_regressions > 0 {
+        process::exit(1);
+    }
+}
+
+/// Times the selected solutions and functions at input sizes doubling up to the largest one, and
+/// prints the exponent of their complexity estimated from the timings.
+fn estimate_complexity(options: &Options) {
+    for &problem_number in &options.problems {
+        let problem = problems::get(problem_number).unwrap();
+        let parameter: Parameter = problem.scale_parameter(options.scale.as_deref()).unwrap();
+        let max: u128 = options
+            .max
+            .unwrap_or_else(|| problem.params(&options.params).get(parameter.name));
+        let sizes: Vec<u128> = valid_sizes(problem, &parameter, max, options);
+
+        for solution in problem.selected_solutions(&options.variants_of(problem_number)) {
+            let name: String = format!("{} of problem {}", solution.name, problem_number);
+            let timings = panic::catch_unwind(AssertUnwindSafe(|| {
+                return complexity::time_sizes(&sizes, |size: u128| {
+                    return (solution.func)(&problem.params(&overrides(options, &parameter, size)));
+                });
+            }));
+
+            match timings {
+                Ok(timings) => print_estimate(&name, parameter.name, &timings),
+                Err(_) => println!("{} : panicked, skipping it", name),
+            }
+        }
+    }
+
+    for name in &options.functions {
+        let function = complexity::get_function(name).unwrap();
+        let sizes: Vec<u128> =
+            complexity::geometric_sizes(options.max.unwrap_or(function.max_size), options.steps);
+
+        print_estimate(name, "n", &complexity::time_sizes(&sizes, function.func));
+    }
+    println!("=============================================================================");
+}
+
+/// Returns the parameter overrides of `options` with `parameter` set to `size`.
+fn overrides(options: &Options, parameter: &Parameter, size: u128) -> Vec<(String, u128)> {
+    let mut overrides: Vec<(String, u128)> = options.params.clone();
+    overrides.push((parameter.name.to_string(), size));
+
+    return overrides;
+}
+
+/// Returns the input sizes up to `max` at which `problem` accepts `parameter`, rounding even sizes
+/// down when the parameter must be odd, and printing the sizes that are skipped.
+fn valid_sizes(
+    problem: &dyn Problem,
+    parameter: &Parameter,
+    max: u128,
+    options: &Options,
+) -> Vec<u128> {
+    let mut sizes: Vec<u128> = complexity::geometric_sizes(max, options.steps)
+        .into_iter()
+        .map(|size: u128| {
+            return match parameter.is_odd && size % 2 == 0 {
+                true => size - 1,
+                false => size,
+            };
+        })
+        .collect();
+    sizes.dedup();
+
+    sizes.retain(|&size: &u128| {
+        return match problem.validate(&problem.params(&overrides(options, parameter, size))) {
+            Ok(()) => true,
+            Err(message) => {
+                println!("Skipping {} = {} : {}", parameter.name, size, message);
+
+                return false;
+            }
+        };
+    });
+
+    return sizes;
+}
+
+/// Prints the time taken at every input size, followed by the estimated complexity.
+fn print_estimate(name: &str, scale: &str, timings: &[(u128, Duration)]) {
+    println!("=============================================================================");
+    for (size, elapsed) in timings {
+        println!("{} = {:<20} took {:.2?}", scale, size, elapsed);
+    }
+
+    match complexity::fit(timings) {
+        Some(estimate) => println!("{} ~ {} (r^2 = {:.3})", name, estimate, estimate.r_squared),
+        None => println!(
+            "{} : not enough input sizes to estimate the complexity",
+            name
+        ),
+    }
+}
+
+/// Runs the selected solutions of `problem`, in child processes if `options` has limits.
+fn run_problem(problem: &dyn Problem, params: &Params, options: &Options) -> Vec<Record> {
+    let variants: Vec<String> = options.variants_of(problem.number());
+
+    return match &options.limits {
+        Some(limits) => isolate::run(problem, params, &variants, &options.sampling, limits),
+        None => problem.run(params, &variants, &options.sampling),
+    };
+}
+
+/// Runs the selected problems and compares the digest of every answer with
+/// `files/answer_digests.txt`. Exits with a non-zero code if any answer does not match.
+fn verify_problems(options: &Options) {
+    let answer_digests: HashMap<u32, Digest> =
+        match verify::parse_answer_digests(verify::ANSWER_DIGESTS) {
+            Ok(answer_digests) => answer_digests,
+            Err(message) => {
+                eprintln!("Invalid answer digests file : {}", message);
+                process::exit(2);
+            }
+        };
+
+    let mut n_passed: usize = 0;
+    let mut n_failed: usize = 0;
+    let mut n_unknown: usize = 0;
+
+    for &problem_number in &options.problems {
+        let problem = match problems::get(problem_number) {
+            Some(problem) => problem,
+            None => continue,
         };
+
+        for record in run_problem(problem, &problem.params(&[]), options) {
+            match verify::verify(&record, &answer_digests) {
+                Verdict::Pass => {
+                    n_passed += 1;
+                    println!("Problem {} {} : PASS", record.problem, record.solution);
+                }
+                Verdict::Fail => {
+                    n_failed += 1;
+                    println!(
+                        "Problem {} {} : FAIL (got {})",
+                        record.problem,
+                        record.solution,
+                        record.result()
+                    );
+                }
+                Verdict::Unknown => {
+                    n_unknown += 1;
+                    println!("Problem {} {} : UNKNOWN", record.problem, record.solution);
+                }
+            }
+        }
+    }
+
+    println!(
+        "Passed : {}, Failed : {}, Unknown : {}",
+        n_passed, n_failed, n_unknown
+    );
+
+    if n_failed > 0 {
+        process::exit(1);
     }
-    println!("=============================================================================")
 }
@@ -247,8 +247,10 @@ pub fn factorial(n: u128) -> HashMap<u128, u128> {
         return HashMap::new();
     }
 
+    let table: PrimeTable = PrimeTable::new(n);
+
     for i in 2..=n {
-        let primes_map: HashMap<u128, u128> = prime_factors_of_n_with_sieve_as_hashmap(i);
+        let primes_map: HashMap<u128, u128> = table.factorize(i);
 
         for (temp_prime, temp_prime_frequency) in primes_map.iter() {
             if ans.contains_key(temp_prime) {
@@ -341,6 +343,12 @@ pub fn factorial_as_u128(n: u128) -> u128 {
 #[allow(dead_code)]
 /// Returns whether a number is prime number or not
 ///
+/// Multiples of the primes below 40 are found by trial division. For the other numbers, a
+/// Miller-Rabin test with the primes up to 37 as witnesses is deterministic for every `n < 2^64`.
+/// Larger numbers go through the Baillie-PSW test instead, which is a strong probable prime test
+/// to base 2 followed by a strong Lucas probable prime test. No composite number is known to pass
+/// it, and none exists below 2^64.
+///
 /// ### Arguments
 ///
 /// * `n` : `u128` - The number that we need to check for primality.
@@ -349,40 +357,415 @@ pub fn factorial_as_u128(n: u128) -> u128 {
 ///
 /// * `bool` - `true` or `false` based on primality test.
 ///
-/// ### Panics
-///
-/// * When `n == 0`
-///
 /// ### Examples
 ///
 /// ```
 /// use project_euler::maths::is_prime as f;
 ///
+/// assert_eq!(f(0), false);
 /// assert_eq!(f(1), false);
 /// assert_eq!(f(2), true);
 /// assert_eq!(f(5), true);
 /// assert_eq!(f(8), false);
+/// assert_eq!(f(2305843009213693951), true); // 2^61 - 1
+/// assert_eq!(f(170141183460469231731687303715884105727), true); // 2^127 - 1
+/// assert_eq!(f(u128::MAX), false);
 ///
 /// ```
 pub fn is_prime(n: u128) -> bool {
-    if n == 0 {
-        panic!("n cannot be 0.")
-    }
+    const SMALL_PRIMES: [u128; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
     if n < 2 {
         return false;
     }
 
-    if n % 2 == 0 {
-        return n == 2;
+    for p in SMALL_PRIMES {
+        if n % p == 0 {
+            return n == p;
+        }
     }
 
-    for i in (3..=int_sqrt(n)).step_by(2) {
-        if n % i == 0 {
-            return false;
+    if n < 41 * 41 {
+        return true;
+    }
+
+    if n <= u64::MAX as u128 {
+        return SMALL_PRIMES
+            .iter()
+            .all(|&base: &u128| is_strong_probable_prime(n, base));
+    }
+
+    return is_strong_probable_prime(n, 2) && is_strong_lucas_probable_prime(n);
+}
+
+#[allow(dead_code)]
+/// Returns `(a + b) % m` without overflowing, for `a < m` and `b < m`.
+fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        return a - (m - b);
+    }
+
+    return a + b;
+}
+
+#[allow(dead_code)]
+/// Returns `(a - b) % m` for `a < m` and `b < m`.
+fn sub_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= b {
+        return a - b;
+    }
+
+    return m - (b - a);
+}
+
+#[allow(dead_code)]
+/// Returns `(a * b) % m` without overflowing, for any `a`, `b` and `m`.
+///
+/// When `m` fits in a `u64`, the product of `a % m` and `b % m` fits in a `u128`. Otherwise the
+/// product is computed by doubling and adding, reducing modulo `m` at every step.
+///
+/// ### Arguments
+///
+/// * `a` : `u128` - The first factor.
+/// * `b` : `u128` - The second factor.
+/// * `m` : `u128` - The modulus.
+///
+/// ### Returns
+///
+/// * `u128` - `(a * b) % m`.
+///
+/// ### Panics
+///
+/// When `m == 0`.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::maths::mul_mod as f;
+///
+/// assert_eq!(f(7, 8, 5), 1);
+/// assert_eq!(f(u128::MAX, u128::MAX, 1000), 25);
+/// assert_eq!(f(u128::MAX - 1, u128::MAX - 1, u128::MAX), 1);
+///
+/// ```
+pub fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    if m == 0 {
+        panic!("m cannot be 0.")
+    }
+
+    let mut a: u128 = a % m;
+    let mut b: u128 = b % m;
+
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    let mut ans: u128 = 0;
+
+    while b > 0 {
+        if b & 1 == 1 {
+            ans = add_mod(ans, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+
+    return ans;
+}
+
+#[allow(dead_code)]
+/// Montgomery multiplication modulo an odd `n`, with `R = 2^128`.
+///
+/// Numbers are multiplied in their Montgomery form `a * R % n`, where a multiplication is a few
+/// word multiplications instead of the 128 additions of [`mul_mod`] for moduli above `u64::MAX`.
+struct Montgomery {
+    n: u128,
+    /// Inverse of `n` modulo `R`.
+    n_inv: u128,
+    /// `R^2 % n`, to convert numbers to their Montgomery form.
+    r_squared: u128,
+}
+
+impl Montgomery {
+    fn new(n: u128) -> Montgomery {
+        // Every step of Newton's iteration doubles the number of correct low bits, starting from
+        // 3 since `n * n % 8 == 1` for an odd `n`.
+        let mut n_inv: u128 = n;
+        for _ in 0..6 {
+            n_inv = n_inv.wrapping_mul(2u128.wrapping_sub(n.wrapping_mul(n_inv)));
+        }
+
+        let r: u128 = 0u128.wrapping_sub(n) % n;
+
+        return Montgomery {
+            n,
+            n_inv,
+            r_squared: mul_mod(r, r, n),
+        };
+    }
+
+    /// Returns the Montgomery form of `a`.
+    fn form_of(&self, a: u128) -> u128 {
+        return self.mul(a % self.n, self.r_squared);
+    }
+
+    /// Returns the number whose Montgomery form is `a`.
+    fn value_of(&self, a: u128) -> u128 {
+        return self.mul(a, 1);
+    }
+
+    /// Returns the high and low halves of the 256 bit product `a * b`.
+    fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+        const MASK: u128 = u64::MAX as u128;
+
+        let (a_hi, a_lo) = (a >> 64, a & MASK);
+        let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+        let lo_lo: u128 = a_lo * b_lo;
+        let lo_hi: u128 = a_lo * b_hi;
+        let hi_lo: u128 = a_hi * b_lo;
+        let hi_hi: u128 = a_hi * b_hi;
+
+        let middle: u128 = (lo_lo >> 64) + (lo_hi & MASK) + (hi_lo & MASK);
+
+        return (
+            hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (middle >> 64),
+            (lo_lo & MASK) | (middle << 64),
+        );
+    }
+
+    /// Returns `a * b / R % n` for `a < n` and `b < n`.
+    fn mul(&self, a: u128, b: u128) -> u128 {
+        let (hi, lo) = Montgomery::widening_mul(a, b);
+        // `a * b - m * n` is a multiple of `R`, and both products have the same low half.
+        let m: u128 = lo.wrapping_mul(self.n_inv);
+        let (m_n_hi, _) = Montgomery::widening_mul(m, self.n);
+
+        if hi >= m_n_hi {
+            return hi - m_n_hi;
+        }
+
+        return hi.wrapping_sub(m_n_hi).wrapping_add(self.n);
+    }
+}
+
+#[allow(dead_code)]
+/// Returns `base^exponent % m`, using [`mul_mod`] so that it never overflows.
+///
+/// Odd moduli above `u64::MAX` use Montgomery multiplication instead, which is much faster.
+///
+/// ### Arguments
+///
+/// * `base` : `u128` - The base.
+/// * `exponent` : `u128` - The exponent.
+/// * `m` : `u128` - The modulus.
+///
+/// ### Returns
+///
+/// * `u128` - `base^exponent % m`.
+///
+/// ### Panics
+///
+/// When `m == 0`.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::maths::pow_mod as f;
+///
+/// assert_eq!(f(2, 10, 1000), 24);
+/// assert_eq!(f(3, 0, 7), 1);
+/// assert_eq!(f(5, 3, 1), 0);
+/// assert_eq!(f(2, 127, u128::MAX), 1 << 127);
+///
+/// ```
+pub fn pow_mod(base: u128, exponent: u128, m: u128) -> u128 {
+    if m == 0 {
+        panic!("m cannot be 0.")
+    }
+
+    let mut exponent: u128 = exponent;
+
+    if m > u64::MAX as u128 && m % 2 == 1 {
+        let montgomery: Montgomery = Montgomery::new(m);
+
+        let mut ans: u128 = montgomery.form_of(1);
+        let mut base: u128 = montgomery.form_of(base);
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                ans = montgomery.mul(ans, base);
+            }
+            base = montgomery.mul(base, base);
+            exponent >>= 1;
+        }
+
+        return montgomery.value_of(ans);
+    }
+
+    let mut ans: u128 = 1 % m;
+    let mut base: u128 = base % m;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            ans = mul_mod(ans, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exponent >>= 1;
+    }
+
+    return ans;
+}
+
+#[allow(dead_code)]
+/// Returns whether the odd number `n > base` is a strong probable prime to `base`, which is one
+/// round of the Miller-Rabin test.
+fn is_strong_probable_prime(n: u128, base: u128) -> bool {
+    let s: u32 = (n - 1).trailing_zeros();
+    let d: u128 = (n - 1) >> s;
+
+    let mut x: u128 = pow_mod(base, d, n);
+
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+
+    for _ in 1..s {
+        x = mul_mod(x, x, n);
+
+        if x == n - 1 {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+#[allow(dead_code)]
+/// Returns the Jacobi symbol `(a / n)` for an odd `n`.
+fn jacobi_symbol(a: u128, n: u128) -> i8 {
+    let mut a: u128 = a % n;
+    let mut n: u128 = n;
+    let mut ans: i8 = 1;
+
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 {
+                ans = -ans;
+            }
+        }
+
+        std::mem::swap(&mut a, &mut n);
+
+        if a % 4 == 3 && n % 4 == 3 {
+            ans = -ans;
+        }
+        a %= n;
+    }
+
+    if n == 1 {
+        return ans;
+    }
+
+    return 0;
+}
+
+#[allow(dead_code)]
+/// Returns whether the odd number `n`, not divisible by any prime below 40, is a strong Lucas
+/// probable prime, with the parameters of Selfridge's method A.
+fn is_strong_lucas_probable_prime(n: u128) -> bool {
+    if is_perfect_square(n) {
+        return false;
+    }
+
+    // The first D in 5, -7, 9, -11, ... with (D / n) = -1, as a residue modulo n.
+    let mut d_abs: u128 = 5;
+    let mut is_negative: bool = false;
+    let d: u128 = loop {
+        let d: u128 = if is_negative {
+            n - d_abs % n
+        } else {
+            d_abs % n
+        };
+
+        match jacobi_symbol(d, n) {
+            -1 => break d,
+            0 if d_abs % n != 0 => return false,
+            _ => {}
+        }
+
+        d_abs += 2;
+        is_negative = !is_negative;
+    };
+
+    // P = 1 and Q = (1 - D) / 4, which is (1 + |D|) / 4 when D < 0.
+    let q: u128 = if is_negative {
+        (d_abs + 1) / 4 % n
+    } else {
+        n - (d_abs - 1) / 4 % n
+    };
+
+    let half = |x: u128| -> u128 {
+        if x % 2 == 0 {
+            return x / 2;
+        }
+        return x / 2 + n / 2 + 1;
+    };
+
+    // Above `u64::MAX`, the terms are kept in Montgomery form. Only additions, halvings and
+    // multiplications are done on them, which all work the same on Montgomery forms.
+    let montgomery: Montgomery = Montgomery::new(n);
+    let is_small: bool = n <= u64::MAX as u128;
+    let to_form = |a: u128| -> u128 {
+        if is_small {
+            return a;
+        }
+        return montgomery.form_of(a);
+    };
+    let mul = |a: u128, b: u128| -> u128 {
+        if is_small {
+            return a * b % n;
+        }
+        return montgomery.mul(a, b);
+    };
+    let (d, q): (u128, u128) = (to_form(d), to_form(q));
+
+    let s: u32 = (n + 1).trailing_zeros();
+    let k: u128 = (n + 1) >> s;
+
+    // U_1, V_1 and Q^1, then the binary digits of k from the most significant one.
+    let mut u: u128 = to_form(1);
+    let mut v: u128 = to_form(1);
+    let mut q_k: u128 = q;
+
+    for bit in (0..(127 - k.leading_zeros())).rev() {
+        u = mul(u, v);
+        v = sub_mod(mul(v, v), add_mod(q_k, q_k, n), n);
+        q_k = mul(q_k, q_k);
+
+        if (k >> bit) & 1 == 1 {
+            let next_u: u128 = half(add_mod(u, v, n));
+            v = half(add_mod(mul(d, u), v, n));
+            u = next_u;
+            q_k = mul(q_k, q);
+        }
+    }
+
+    if u == 0 || v == 0 {
+        return true;
+    }
+
+    for _ in 1..s {
+        v = sub_mod(mul(v, v), add_mod(q_k, q_k, n), n);
+        q_k = mul(q_k, q_k);
+
+        if v == 0 {
+            return true;
         }
     }
 
-    return true;
+    return false;
 }
 
 #[allow(dead_code)]
@@ -425,7 +808,7 @@ pub fn collatz_sequence(n: u128) -> Vec<u128> {
         match n % 2 == 0 {
             true => {
                 ans.push(n);
-                n = n / 2;
+                n /= 2;
             }
             false => {
                 ans.push(n);
@@ -477,7 +860,7 @@ pub fn collatz_sequence_length(n: u128) -> u128 {
         match n % 2 == 0 {
             true => {
                 length += 1;
-                n = n / 2;
+                n /= 2;
             }
             false => {
                 length += 1;
@@ -840,7 +1223,7 @@ pub fn max_prod_in_grid(grid: Vec<Vec<u128>>, n_adjacent: u128) -> u128 {
     let mut max_prod: u128 = 0;
 
     // horizontal
-    for i in 0..(n_rows as usize) {
+    for i in 0..n_rows {
         for j in 0..(n_cols + 1 - n_adjacent as usize) {
             let mut longitudinal_prod: u128 = 1;
             for n in 0..n_adjacent as usize {
@@ -852,7 +1235,7 @@ pub fn max_prod_in_grid(grid: Vec<Vec<u128>>, n_adjacent: u128) -> u128 {
 
     // vertical
     for i in 0..(n_rows + 1 - n_adjacent as usize) {
-        for j in 0..(n_cols as usize) {
+        for j in 0..n_cols {
             let mut vertical_prod: u128 = 1;
             for n in 0..n_adjacent as usize {
                 vertical_prod *= grid[i + n][j];
@@ -976,7 +1359,7 @@ pub fn max_sum_in_grid(grid: Vec<Vec<u128>>, n_adjacent: u128) -> u128 {
     let mut max_sum: u128 = 0;
 
     // horizontal
-    for i in 0..(n_rows as usize) {
+    for i in 0..n_rows {
         for j in 0..(n_cols + 1 - n_adjacent as usize) {
             let mut longitudinal_sum: u128 = 0;
             for n in 0..n_adjacent as usize {
@@ -988,7 +1371,7 @@ pub fn max_sum_in_grid(grid: Vec<Vec<u128>>, n_adjacent: u128) -> u128 {
 
     // vertical
     for i in 0..(n_rows + 1 - n_adjacent as usize) {
-        for j in 0..(n_cols as usize) {
+        for j in 0..n_cols {
             let mut vertical_sum: u128 = 0;
             for n in 0..n_adjacent as usize {
                 vertical_sum += grid[i + n][j];
@@ -1073,7 +1456,7 @@ pub fn int_sqrt(n: u128) -> u128 {
     }
 
     let mut low: u128 = 1;
-    let mut high: u128 = std::u64::MAX as u128;
+    let mut high: u128 = u64::MAX as u128;
 
     while low <= high {
         let mid: u128 = low + (high - low) / 2;
@@ -1119,7 +1502,7 @@ pub fn is_palindrome(n: u128) -> bool {
 
     while number > 0 {
         reversed = reversed * 10 + number % 10;
-        number = number / 10;
+        number /= 10;
     }
 
     return reversed == n;
@@ -1440,79 +1823,304 @@ pub fn prime_factors_of_n_without_sieve_as_vec(n: u128) -> Vec<u128> {
 }
 
 #[allow(dead_code)]
-/// Returns a list of primes upto and including `n` without using [`sieve_of_eratosthenes`] as it requires a lot of memory.
+/// Returns the greatest common divisor of `a` and `b`, which is 0 when both are 0.
 ///
-/// [`sieve_of_eratosthenes`]: fn.sieve_of_eratosthenes.html
+/// ### Examples
 ///
-/// If `n == 0` or `n == 1`, an empty vector (`vec![]`) is returned.
+/// ```
+/// use project_euler::maths::gcd as f;
+///
+/// assert_eq!(f(0, 0), 0);
+/// assert_eq!(f(0, 5), 5);
+/// assert_eq!(f(12, 18), 6);
+/// assert_eq!(f(17, 5), 1);
+///
+/// ```
+pub fn gcd(a: u128, b: u128) -> u128 {
+    let mut a: u128 = a;
+    let mut b: u128 = b;
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    return a;
+}
+
+#[allow(dead_code)]
+/// Returns a divisor of the composite number `n` other than 1 and `n`, found with Brent's
+/// variant of Pollard's rho algorithm.
+///
+/// The sequence `x -> x^2 + c` modulo `n` cycles after about `sqrt(p)` steps modulo the smallest
+/// prime factor `p` of `n`, which shows up as a common divisor of `n` and the difference of two
+/// terms. The differences are multiplied together and `gcd` is taken once every 128 steps. If
+/// the cycle modulo `n` is hit first, it is tried again with the next `c`.
+///
+/// For moduli above `u64::MAX`, the terms are kept in Montgomery form, which does not change
+/// their common divisors with `n`.
 ///
 /// ### Arguments
 ///
-/// * `n` : `u128` - The inclusive number upto which we are trying to find primes.
+/// * `n` : `u128` - A composite number.
 ///
 /// ### Returns
 ///
-/// * `Vec<u128>` - List of primes upto and including `n`.
+/// * `u128` - A divisor `d` of `n`, with `1 < d < n`. It need not be prime.
+///
+/// ### Panics
+///
+/// When `n` is prime or smaller than 4, as it would never return.
 ///
 /// ### Examples
 ///
 /// ```
-/// use project_euler::maths::primes_upto_n_without_sieve as f;
+/// use project_euler::maths::pollard_brent_rho as f;
 ///
-/// assert_eq!(f(0), vec![]);
-/// assert_eq!(f(1), vec![]);
-/// assert_eq!(f(2), vec![2]);
-/// assert_eq!(f(12), vec![2, 3, 5, 7, 11]);
-/// assert_eq!(f(17), vec![2, 3, 5, 7, 11, 13, 17]);
+/// assert_eq!(f(4), 2);
+///
+/// let d: u128 = f(600851475143);
+/// assert!(d > 1 && d < 600851475143 && 600851475143 % d == 0);
+///
+/// let n: u128 = 2147483647 * 2305843009213693951;
+/// let d: u128 = f(n);
+/// assert!(d == 2147483647 || d == 2305843009213693951);
 ///
 /// ```
-pub fn primes_upto_n_without_sieve(n: u128) -> Vec<u128> {
-    let mut ans: Vec<u128> = Vec::new();
+pub fn pollard_brent_rho(n: u128) -> u128 {
+    if n < 4 || is_prime(n) {
+        panic!("n must be a composite number.")
+    }
 
-    if n < 2 {
-        return ans;
+    return pollard_brent_rho_unchecked(n);
+}
+
+/// [`pollard_brent_rho`] without checking that `n` is composite, for callers that already know it,
+/// like [`prime_factors_of_n_with_pollard_rho_as_hashmap`] which tests every part with [`is_prime`]
+/// before splitting it.
+///
+/// [`pollard_brent_rho`]: fn.pollard_brent_rho.html
+/// [`prime_factors_of_n_with_pollard_rho_as_hashmap`]: fn.prime_factors_of_n_with_pollard_rho_as_hashmap.html
+/// [`is_prime`]: fn.is_prime.html
+fn pollard_brent_rho_unchecked(n: u128) -> u128 {
+    if n % 2 == 0 {
+        return 2;
     }
 
-    ans.push(2);
+    let montgomery: Montgomery = Montgomery::new(n);
+    let mul = |a: u128, b: u128| -> u128 {
+        if n <= u64::MAX as u128 {
+            return a * b % n;
+        }
+        return montgomery.mul(a, b);
+    };
 
-    for i in (3..=n).step_by(2) {
-        if !ans.iter().any(|prime| i % prime == 0) {
-            ans.push(i);
+    const BATCH: u128 = 128;
+
+    for c in 1..n {
+        let next = |x: u128| -> u128 { add_mod(mul(x, x), c, n) };
+
+        let mut y: u128 = 2;
+        let mut x: u128 = y;
+        let mut saved_y: u128 = y;
+        let mut product: u128 = 1;
+        let mut divisor: u128 = 1;
+        let mut length: u128 = 1;
+
+        while divisor == 1 {
+            x = y;
+            for _ in 0..length {
+                y = next(y);
+            }
+
+            let mut steps: u128 = 0;
+            while steps < length && divisor == 1 {
+                saved_y = y;
+                for _ in 0..BATCH.min(length - steps) {
+                    y = next(y);
+                    product = mul(product, x.abs_diff(y));
+                }
+                divisor = gcd(product, n);
+                steps += BATCH;
+            }
+
+            length *= 2;
+        }
+
+        // The batch overshot, so the steps since the last gcd are taken again one at a time.
+        if divisor == n {
+            loop {
+                saved_y = next(saved_y);
+                divisor = gcd(x.abs_diff(saved_y), n);
+
+                if divisor > 1 {
+                    break;
+                }
+            }
+        }
+
+        if divisor != n {
+            return divisor;
         }
     }
 
-    return ans;
+    unreachable!();
 }
 
 #[allow(dead_code)]
-/// Returns a list of primes upto the inclusive limit `n` using __sieve of eratosthenes__. [wiki](https://en.wikipedia.org/wiki/Sieve_of_Eratosthenes).
+/// Returns a Hashmap with keys as primes and values as the number of times the prime is present in the number,
+/// without a list of primes.
 ///
-/// It is done by iteratively marking the multiples of primes as composite upto the given limit. Once the algorithms stops, we are left with prime numbers
+/// The primes below 1000 are divided out first. Whatever is left is split with [`pollard_brent_rho`]
+/// until every part is prime according to [`is_prime`].
 ///
-/// _It uses a lot of memory. Use_ [`primes_upto_n_without_sieve`] _if `n` is very large_
+/// Only numbers whose second largest prime factor has at most 10 digits are guaranteed to be
+/// factored in a few milliseconds, which includes every `n` below `10^20`. Beyond that the time
+/// grows with the square root of that factor: about a second at 15 digits, several seconds at 17
+/// digits and minutes for a product of two primes of 19 or 20 digits. There is no SQUFOF or
+/// elliptic curve stage to split such balanced factors faster.
 ///
-/// [`primes_upto_n_without_sieve`]: fn.primes_upto_n_without_sieve.html
+/// [`pollard_brent_rho`]: fn.pollard_brent_rho.html
+/// [`is_prime`]: fn.is_prime.html
 ///
-/// If `n == 0` or `n == 1`, an empty vector (`vec![]`) is returned.
+/// If `n == 0` or `n == 1`, an empty Hashmap is returned.
 ///
 /// ### Arguments
 ///
-/// * `n` : `u128` - the inclusive upper limit upto which we need to find primes.
+/// * `n` : `u128` - The number for which we are trying to find the prime factors and their frequencies.
 ///
 /// ### Returns
 ///
-/// * `Vec<u128>` - List of primes upto and including `n`.
+/// * `HashMap<u128, u128>` - Hashmap with primes as keys and their frequency as values.
 ///
 /// ### Examples
 ///
 /// ```
-/// use project_euler::maths::sieve_of_eratosthenes as f;
-///
-/// assert_eq!(f(0), vec![]);
-/// assert_eq!(f(1), vec![]);
-/// assert_eq!(f(3), vec![2, 3]);
-/// assert_eq!(f(10), vec![2, 3, 5, 7]);
-/// assert_eq!(f(11), vec![2, 3, 5, 7, 11]);
+/// use project_euler::maths::prime_factors_of_n_with_pollard_rho_as_hashmap as f;
+/// use std::collections::HashMap;
+///
+/// assert_eq!(f(0), HashMap::new());
+/// assert_eq!(f(1), HashMap::new());
+/// assert_eq!(f(60), HashMap::from_iter(vec![(2, 2), (3, 1), (5, 1)]));
+/// assert_eq!(
+///     f(600851475143),
+///     HashMap::from_iter(vec![(71, 1), (839, 1), (1471, 1), (6857, 1)])
+/// );
+/// assert_eq!(
+///     f(2147483647 * 2305843009213693951),
+///     HashMap::from_iter(vec![(2147483647, 1), (2305843009213693951, 1)])
+/// );
+///
+/// ```
+pub fn prime_factors_of_n_with_pollard_rho_as_hashmap(n: u128) -> HashMap<u128, u128> {
+    let mut primes_with_frequency: HashMap<u128, u128> = HashMap::new();
+
+    if n < 2 {
+        return primes_with_frequency;
+    }
+
+    let mut n: u128 = n;
+    let mut divisor: u128 = 2;
+
+    while divisor < 1000 && divisor * divisor <= n {
+        while n % divisor == 0 {
+            *primes_with_frequency.entry(divisor).or_insert(0) += 1;
+            n /= divisor;
+        }
+        divisor += if divisor == 2 { 1 } else { 2 };
+    }
+
+    let mut to_split: Vec<u128> = Vec::new();
+    if n > 1 {
+        to_split.push(n);
+    }
+
+    while let Some(number) = to_split.pop() {
+        if is_prime(number) {
+            *primes_with_frequency.entry(number).or_insert(0) += 1;
+        } else {
+            let divisor: u128 = pollard_brent_rho_unchecked(number);
+            to_split.push(divisor);
+            to_split.push(number / divisor);
+        }
+    }
+
+    return primes_with_frequency;
+}
+
+#[allow(dead_code)]
+/// Returns a list of primes upto and including `n` without using [`sieve_of_eratosthenes`] as it requires a lot of memory.
+///
+/// [`sieve_of_eratosthenes`]: fn.sieve_of_eratosthenes.html
+///
+/// If `n == 0` or `n == 1`, an empty vector (`vec![]`) is returned.
+///
+/// ### Arguments
+///
+/// * `n` : `u128` - The inclusive number upto which we are trying to find primes.
+///
+/// ### Returns
+///
+/// * `Vec<u128>` - List of primes upto and including `n`.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::maths::primes_upto_n_without_sieve as f;
+///
+/// assert_eq!(f(0), vec![]);
+/// assert_eq!(f(1), vec![]);
+/// assert_eq!(f(2), vec![2]);
+/// assert_eq!(f(12), vec![2, 3, 5, 7, 11]);
+/// assert_eq!(f(17), vec![2, 3, 5, 7, 11, 13, 17]);
+///
+/// ```
+pub fn primes_upto_n_without_sieve(n: u128) -> Vec<u128> {
+    let mut ans: Vec<u128> = Vec::new();
+
+    if n < 2 {
+        return ans;
+    }
+
+    ans.push(2);
+
+    for i in (3..=n).step_by(2) {
+        if !ans.iter().any(|prime| i % prime == 0) {
+            ans.push(i);
+        }
+    }
+
+    return ans;
+}
+
+#[allow(dead_code)]
+/// Returns a list of primes upto the inclusive limit `n` using __sieve of eratosthenes__. [wiki](https://en.wikipedia.org/wiki/Sieve_of_Eratosthenes).
+///
+/// It is done by iteratively marking the multiples of primes as composite upto the given limit. Once the algorithms stops, we are left with prime numbers
+///
+/// _It uses a lot of memory. Use_ [`primes_upto_n_without_sieve`] _if `n` is very large_
+///
+/// [`primes_upto_n_without_sieve`]: fn.primes_upto_n_without_sieve.html
+///
+/// If `n == 0` or `n == 1`, an empty vector (`vec![]`) is returned.
+///
+/// ### Arguments
+///
+/// * `n` : `u128` - the inclusive upper limit upto which we need to find primes.
+///
+/// ### Returns
+///
+/// * `Vec<u128>` - List of primes upto and including `n`.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::maths::sieve_of_eratosthenes as f;
+///
+/// assert_eq!(f(0), vec![]);
+/// assert_eq!(f(1), vec![]);
+/// assert_eq!(f(3), vec![2, 3]);
+/// assert_eq!(f(10), vec![2, 3, 5, 7]);
+/// assert_eq!(f(11), vec![2, 3, 5, 7, 11]);
 ///
 /// ```
 pub fn sieve_of_eratosthenes(n: u128) -> Vec<u128> {
@@ -1546,6 +2154,576 @@ pub fn sieve_of_eratosthenes(n: u128) -> Vec<u128> {
     }
 }
 
+#[allow(dead_code)]
+/// Primes up to a limit, sieved once and then queried as many times as needed.
+///
+/// Use it instead of calling [`sieve_of_eratosthenes`] or
+/// [`prime_factors_of_n_with_sieve_as_hashmap`] inside a loop, which sieve again on every call.
+///
+/// [`sieve_of_eratosthenes`]: fn.sieve_of_eratosthenes.html
+/// [`prime_factors_of_n_with_sieve_as_hashmap`]: fn.prime_factors_of_n_with_sieve_as_hashmap.html
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::maths::PrimeTable;
+/// use std::collections::HashMap;
+///
+/// let table: PrimeTable = PrimeTable::new(100);
+///
+/// assert!(table.is_prime(97));
+/// assert_eq!(table.nth(4), Some(7));
+/// assert_eq!(table.pi(10), 4);
+/// assert_eq!(table.factorize(360), HashMap::from_iter(vec![(2, 3), (3, 2), (5, 1)]));
+/// assert_eq!(table.iter().take(5).collect::<Vec<u128>>(), vec![2, 3, 5, 7, 11]);
+///
+/// ```
+pub struct PrimeTable {
+    limit: u128,
+    /// Whether every number up to `limit` is prime, indexed by the number.
+    is_prime: Vec<bool>,
+    /// Primes up to `limit` in increasing order.
+    primes: Vec<u128>,
+}
+
+impl PrimeTable {
+    /// Sieves the primes up to and including `limit`.
+    pub fn new(limit: u128) -> PrimeTable {
+        let primes: Vec<u128> = sieve_of_eratosthenes(limit);
+
+        let mut is_prime: Vec<bool> = vec![false; limit as usize + 1];
+        for &prime in &primes {
+            is_prime[prime as usize] = true;
+        }
+
+        return PrimeTable {
+            limit,
+            is_prime,
+            primes,
+        };
+    }
+
+    /// Returns the inclusive limit the table was sieved up to.
+    pub fn limit(&self) -> u128 {
+        return self.limit;
+    }
+
+    /// Returns the primes up to the limit in increasing order.
+    pub fn primes(&self) -> &[u128] {
+        return &self.primes;
+    }
+
+    /// Returns an iterator over the primes up to the limit in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = u128> + '_ {
+        return self.primes.iter().copied();
+    }
+
+    /// Returns whether `n` is prime, looking it up when `n` is within the limit and calling
+    /// [`is_prime`] otherwise.
+    ///
+    /// [`is_prime`]: fn.is_prime.html
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::maths::PrimeTable;
+    ///
+    /// let table: PrimeTable = PrimeTable::new(10);
+    ///
+    /// assert_eq!(table.is_prime(0), false);
+    /// assert_eq!(table.is_prime(7), true);
+    /// assert_eq!(table.is_prime(13), true);
+    ///
+    /// ```
+    pub fn is_prime(&self, n: u128) -> bool {
+        if n <= self.limit {
+            return self.is_prime[n as usize];
+        }
+
+        return is_prime(n);
+    }
+
+    /// Returns the `n`<sup>th</sup> prime with __1-based indexing__, or `None` if `n == 0` or
+    /// there are fewer than `n` primes up to the limit.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::maths::PrimeTable;
+    ///
+    /// let table: PrimeTable = PrimeTable::new(7000);
+    ///
+    /// assert_eq!(table.nth(0), None);
+    /// assert_eq!(table.nth(1), Some(2));
+    /// assert_eq!(table.nth(888), Some(6907));
+    /// assert_eq!(table.nth(1000), None);
+    ///
+    /// ```
+    pub fn nth(&self, n: usize) -> Option<u128> {
+        return n
+            .checked_sub(1)
+            .and_then(|index| self.primes.get(index).copied());
+    }
+
+    /// Returns the number of primes up to and including `x`.
+    ///
+    /// ### Panics
+    ///
+    /// When `x` is above the limit.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::maths::PrimeTable;
+    ///
+    /// let table: PrimeTable = PrimeTable::new(1000);
+    ///
+    /// assert_eq!(table.pi(1), 0);
+    /// assert_eq!(table.pi(2), 1);
+    /// assert_eq!(table.pi(100), 25);
+    /// assert_eq!(table.pi(1000), 168);
+    ///
+    /// ```
+    pub fn pi(&self, x: u128) -> usize {
+        if x > self.limit {
+            panic!(
+                "{}",
+                format!("x : {} is above the limit : {} of the table", x, self.limit)
+            );
+        }
+
+        return self.primes.partition_point(|&prime: &u128| prime <= x);
+    }
+
+    /// Returns a Hashmap with keys as primes and values as the number of times the prime is
+    /// present in `n`, found by trial division with the primes of the table.
+    ///
+    /// The factorization needs no other primes when `n` is at most the square of the limit.
+    /// Whatever is left of a larger `n` is factored with
+    /// [`prime_factors_of_n_with_pollard_rho_as_hashmap`].
+    ///
+    /// [`prime_factors_of_n_with_pollard_rho_as_hashmap`]: fn.prime_factors_of_n_with_pollard_rho_as_hashmap.html
+    ///
+    /// If `n == 0` or `n == 1`, an empty Hashmap is returned.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::maths::PrimeTable;
+    /// use std::collections::HashMap;
+    ///
+    /// let table: PrimeTable = PrimeTable::new(100);
+    ///
+    /// assert_eq!(table.factorize(0), HashMap::new());
+    /// assert_eq!(table.factorize(1), HashMap::new());
+    /// assert_eq!(table.factorize(9973), HashMap::from_iter(vec![(9973, 1)]));
+    /// assert_eq!(
+    ///     table.factorize(600851475143),
+    ///     HashMap::from_iter(vec![(71, 1), (839, 1), (1471, 1), (6857, 1)])
+    /// );
+    ///
+    /// ```
+    pub fn factorize(&self, n: u128) -> HashMap<u128, u128> {
+        let mut primes_with_frequency: HashMap<u128, u128> = HashMap::new();
+
+        if n < 2 {
+            return primes_with_frequency;
+        }
+
+        let mut n: u128 = n;
+
+        for &prime in &self.primes {
+            if prime * prime > n {
+                break;
+            }
+
+            while n % prime == 0 {
+                *primes_with_frequency.entry(prime).or_insert(0) += 1;
+                n /= prime;
+            }
+        }
+
+        if n > 1 {
+            // Without a prime factor up to the limit, `n` is prime if it is at most the square of
+            // the limit.
+            let is_prime_left: bool = self
+                .limit
+                .checked_mul(self.limit)
+                .is_none_or(|square: u128| n <= square);
+
+            if is_prime_left {
+                *primes_with_frequency.entry(n).or_insert(0) += 1;
+            } else {
+                for (prime, frequency) in prime_factors_of_n_with_pollard_rho_as_hashmap(n) {
+                    *primes_with_frequency.entry(prime).or_insert(0) += frequency;
+                }
+            }
+        }
+
+        return primes_with_frequency;
+    }
+}
+
+#[allow(dead_code)]
+/// Smallest prime factor of every number up to a limit, found with a __linear sieve__.
+/// [wiki](https://en.wikipedia.org/wiki/Sieve_of_Eratosthenes#Euler's_sieve).
+///
+/// Every composite number is crossed out exactly once, by its smallest prime factor, so building
+/// it takes `O(limit)` time. Factorizing any number up to the limit then only takes one division
+/// per prime factor, which suits workloads that factorize every number up to the limit.
+///
+/// The factors are stored as `u32` to use 4 bytes per number.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::maths::SmallestPrimeFactorSieve;
+/// use std::collections::HashMap;
+///
+/// let sieve: SmallestPrimeFactorSieve = SmallestPrimeFactorSieve::new(100);
+///
+/// assert_eq!(sieve.smallest_prime_factor(91), 7);
+/// assert_eq!(sieve.factorize(60), HashMap::from_iter(vec![(2, 2), (3, 1), (5, 1)]));
+/// assert_eq!(sieve.primes().len(), 25);
+///
+/// ```
+pub struct SmallestPrimeFactorSieve {
+    /// Smallest prime factor of every number up to the limit, indexed by the number, with 0 for
+    /// 0 and 1.
+    smallest_prime_factors: Vec<u32>,
+    /// Primes up to the limit in increasing order.
+    primes: Vec<u128>,
+}
+
+impl SmallestPrimeFactorSieve {
+    /// Sieves the smallest prime factors of the numbers up to and including `limit`.
+    ///
+    /// ### Panics
+    ///
+    /// When `limit > u32::MAX`.
+    pub fn new(limit: u128) -> SmallestPrimeFactorSieve {
+        if limit > u32::MAX as u128 {
+            panic!(
+                "{}",
+                format!("limit : {} cannot be greater than {}", limit, u32::MAX)
+            );
+        }
+
+        let limit: usize = limit as usize;
+
+        let mut smallest_prime_factors: Vec<u32> = vec![0; limit + 1];
+        let mut primes: Vec<u128> = Vec::new();
+
+        for i in 2..=limit {
+            if smallest_prime_factors[i] == 0 {
+                smallest_prime_factors[i] = i as u32;
+                primes.push(i as u128);
+            }
+
+            let smallest_prime_factor: u128 = smallest_prime_factors[i] as u128;
+
+            for &prime in &primes {
+                let multiple: usize = i * prime as usize;
+
+                if prime > smallest_prime_factor || multiple > limit {
+                    break;
+                }
+
+                smallest_prime_factors[multiple] = prime as u32;
+            }
+        }
+
+        return SmallestPrimeFactorSieve {
+            smallest_prime_factors,
+            primes,
+        };
+    }
+
+    /// Returns the inclusive limit the sieve was built up to.
+    pub fn limit(&self) -> u128 {
+        return (self.smallest_prime_factors.len() - 1) as u128;
+    }
+
+    /// Returns the primes up to the limit in increasing order.
+    pub fn primes(&self) -> &[u128] {
+        return &self.primes;
+    }
+
+    /// Returns the smallest prime factor of `n`, which is 0 for 0 and 1.
+    ///
+    /// ### Panics
+    ///
+    /// When `n` is above the limit.
+    pub fn smallest_prime_factor(&self, n: u128) -> u128 {
+        return self.smallest_prime_factors[n as usize] as u128;
+    }
+
+    /// Returns a Hashmap with keys as primes and values as the number of times the prime is
+    /// present in `n`, in the same form as [`prime_factors_of_n_with_sieve_as_hashmap`].
+    ///
+    /// [`prime_factors_of_n_with_sieve_as_hashmap`]: fn.prime_factors_of_n_with_sieve_as_hashmap.html
+    ///
+    /// If `n == 0` or `n == 1`, an empty Hashmap is returned.
+    ///
+    /// ### Panics
+    ///
+    /// When `n` is above the limit.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::maths::SmallestPrimeFactorSieve;
+    /// use std::collections::HashMap;
+    ///
+    /// let sieve: SmallestPrimeFactorSieve = SmallestPrimeFactorSieve::new(1000);
+    ///
+    /// assert_eq!(sieve.factorize(0), HashMap::new());
+    /// assert_eq!(sieve.factorize(1), HashMap::new());
+    /// assert_eq!(sieve.factorize(997), HashMap::from_iter(vec![(997, 1)]));
+    /// assert_eq!(sieve.factorize(1000), HashMap::from_iter(vec![(2, 3), (5, 3)]));
+    ///
+    /// ```
+    pub fn factorize(&self, n: u128) -> HashMap<u128, u128> {
+        let mut primes_with_frequency: HashMap<u128, u128> = HashMap::new();
+        let mut n: u128 = n;
+
+        while n > 1 {
+            let prime: u128 = self.smallest_prime_factor(n);
+
+            *primes_with_frequency.entry(prime).or_insert(0) += 1;
+            n /= prime;
+        }
+
+        return primes_with_frequency;
+    }
+
+    /// Returns the sum of all the divisors of `n` including `1` and `n`, like
+    /// [`sum_of_all_divisors`], from the factorization of `n`.
+    ///
+    /// [`sum_of_all_divisors`]: fn.sum_of_all_divisors.html
+    ///
+    /// If `n == 0`, 0 is returned.
+    ///
+    /// ### Panics
+    ///
+    /// When `n` is above the limit.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::maths::SmallestPrimeFactorSieve;
+    ///
+    /// let sieve: SmallestPrimeFactorSieve = SmallestPrimeFactorSieve::new(10000);
+    ///
+    /// assert_eq!(sieve.sum_of_all_divisors(0), 0);
+    /// assert_eq!(sieve.sum_of_all_divisors(1), 1);
+    /// assert_eq!(sieve.sum_of_all_divisors(23), 24);
+    /// assert_eq!(sieve.sum_of_all_divisors(5040), 19344);
+    ///
+    /// ```
+    pub fn sum_of_all_divisors(&self, n: u128) -> u128 {
+        if n == 0 {
+            return 0;
+        }
+
+        return self
+            .factorize(n)
+            .iter()
+            .map(|(&prime, &power)| (prime.pow(power as u32 + 1) - 1) / (prime - 1))
+            .product();
+    }
+}
+
+/// Number of integers sieved at a time by [`SegmentedSieve`], so that a segment fits in the L1
+/// cache.
+pub const SEGMENT_SIZE: u128 = 32 * 1024;
+
+#[allow(dead_code)]
+/// Iterator over the primes in an inclusive range `[low, high]`, sieved one segment of
+/// [`SEGMENT_SIZE`] integers at a time. Created by [`segmented_sieve_iter`].
+///
+/// [`segmented_sieve_iter`]: fn.segmented_sieve_iter.html
+///
+/// Only a single segment and the primes up to the square root of the current segment are kept in
+/// memory. Those base primes are themselves found by a segmented sieve over `[2, sqrt(high)]`, as
+/// the segments need them, so primes far beyond the reach of [`sieve_of_eratosthenes`] can be
+/// enumerated, like the ones in `[10^12, 10^12 + 10^6]`.
+///
+/// `high` is at most `u64::MAX`, so every base prime is below `2^32` and takes 4 bytes. They take
+/// less than 3 MB up to `10^14`, about 200 MB near `10^18`, and at most 812 MB for the 203 million
+/// primes below `2^32` near `u64::MAX`, where sieving them takes about half a minute even when
+/// the range itself is small.
+///
+/// [`sieve_of_eratosthenes`]: fn.sieve_of_eratosthenes.html
+pub struct SegmentedSieve {
+    /// Primes up to the square root of the current segment, whose multiples are crossed out.
+    base_primes: Vec<u32>,
+    /// Sieve over `[2, sqrt(high)]` that yields the base primes, or `None` once it is exhausted.
+    base_sieve: Option<Box<SegmentedSieve>>,
+    /// First integer of the next segment, or `None` once `high` has been sieved.
+    next_low: Option<u128>,
+    high: u128,
+    /// Primes of the current segment that have not been returned yet.
+    segment: std::vec::IntoIter<u128>,
+}
+
+impl SegmentedSieve {
+    /// Takes base primes from `base_sieve` until they reach the square root of `high`.
+    fn extend_base_primes(&mut self, high: u128) {
+        while let Some(base_sieve) = &mut self.base_sieve {
+            if let Some(&prime) = self.base_primes.last() {
+                if (prime as u128).pow(2) > high {
+                    return;
+                }
+            }
+
+            match base_sieve.next() {
+                Some(prime) => self.base_primes.push(prime as u32),
+                None => self.base_sieve = None,
+            }
+        }
+    }
+
+    /// Returns the primes in `[low, high]`, which must have at most [`SEGMENT_SIZE`] integers.
+    fn sieve_segment(&self, low: u128, high: u128) -> Vec<u128> {
+        let mut is_prime: Vec<bool> = vec![true; (high - low + 1) as usize];
+
+        // `high` is at most `u64::MAX`, so the offsets are computed with u64 divisions, which are
+        // much faster than u128 ones.
+        let (low_u64, high_u64): (u64, u64) = (low as u64, high as u64);
+
+        for &prime in &self.base_primes {
+            let prime: u64 = prime as u64;
+            let square: u64 = prime * prime;
+
+            if square > high_u64 {
+                break;
+            }
+
+            let mut offset: usize = match square >= low_u64 {
+                true => (square - low_u64) as usize,
+                false => ((prime - low_u64 % prime) % prime) as usize,
+            };
+
+            while offset < is_prime.len() {
+                is_prime[offset] = false;
+                offset += prime as usize;
+            }
+        }
+
+        return is_prime
+            .iter()
+            .enumerate()
+            .filter(|&(offset, &is_prime)| is_prime && low + offset as u128 >= 2)
+            .map(|(offset, _)| low + offset as u128)
+            .collect();
+    }
+}
+
+impl Iterator for SegmentedSieve {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<u128> {
+        loop {
+            if let Some(prime) = self.segment.next() {
+                return Some(prime);
+            }
+
+            let low: u128 = self.next_low?;
+            let high: u128 = low.saturating_add(SEGMENT_SIZE - 1).min(self.high);
+
+            self.next_low = if high < self.high {
+                Some(high + 1)
+            } else {
+                None
+            };
+            self.extend_base_primes(high);
+            self.segment = self.sieve_segment(low, high).into_iter();
+        }
+    }
+}
+
+#[allow(dead_code)]
+/// Returns an iterator over the primes in the inclusive range `[low, high]`, using a segmented
+/// __sieve of eratosthenes__. See [`SegmentedSieve`].
+///
+/// If `low > high`, the iterator is empty.
+///
+/// ### Panics
+///
+/// * When `high > u64::MAX`
+///
+/// ### Arguments
+///
+/// * `low` : `u128` - the inclusive lower limit of the range.
+/// * `high` : `u128` - the inclusive upper limit of the range.
+///
+/// ### Returns
+///
+/// * `SegmentedSieve` - Iterator over the primes in `[low, high]` in increasing order.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::maths::segmented_sieve_iter as f;
+///
+/// assert_eq!(f(0, 10).collect::<Vec<u128>>(), vec![2, 3, 5, 7]);
+/// assert_eq!(f(1_000_000_000_000, 1_000_001_000_000).next(), Some(1_000_000_000_039));
+/// assert_eq!(f(10, 0).next(), None);
+///
+/// ```
+pub fn segmented_sieve_iter(low: u128, high: u128) -> SegmentedSieve {
+    if high > u64::MAX as u128 {
+        panic!("high cannot be larger than u64::MAX : {}.", u64::MAX);
+    }
+
+    return SegmentedSieve {
+        base_primes: Vec::new(),
+        base_sieve: if high >= 4 {
+            Some(Box::new(segmented_sieve_iter(2, int_sqrt(high))))
+        } else {
+            None
+        },
+        next_low: if low <= high { Some(low) } else { None },
+        high,
+        segment: Vec::new().into_iter(),
+    };
+}
+
+#[allow(dead_code)]
+/// Returns a list of primes in the inclusive range `[low, high]` using a segmented __sieve of
+/// eratosthenes__. See [`SegmentedSieve`].
+///
+/// If `low > high`, an empty vector (`vec![]`) is returned.
+///
+/// ### Panics
+///
+/// * When `high > u64::MAX`
+///
+/// ### Arguments
+///
+/// * `low` : `u128` - the inclusive lower limit of the range.
+/// * `high` : `u128` - the inclusive upper limit of the range.
+///
+/// ### Returns
+///
+/// * `Vec<u128>` - List of primes in `[low, high]`.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::maths::segmented_sieve as f;
+///
+/// assert_eq!(f(0, 1), vec![]);
+/// assert_eq!(f(0, 11), vec![2, 3, 5, 7, 11]);
+/// assert_eq!(f(90, 110), vec![97, 101, 103, 107, 109]);
+/// assert_eq!(f(1_000_000_000_000, 1_000_000_000_100), vec![1_000_000_000_039, 1_000_000_000_061, 1_000_000_000_063, 1_000_000_000_091]);
+///
+/// ```
+pub fn segmented_sieve(low: u128, high: u128) -> Vec<u128> {
+    return segmented_sieve_iter(low, high).collect();
+}
+
 #[allow(dead_code)]
 /// Returns the value of `base` raised to `power` as a Vector of digits.
 ///
@@ -1662,7 +2840,7 @@ pub fn get_power_of_a_number(base: Vec<u8>, power: Vec<u8>) -> Vec<u8> {
 
             // power.reverse();
         } else {
-            power[power_vec_len - 1] = power[power_vec_len - 1] - 1;
+            power[power_vec_len - 1] -= 1;
         }
     }
 
@@ -1992,6 +3170,246 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_prime() {
+        let test_cases: Vec<(u128, bool)> = vec![
+            (0, false),
+            (1, false),
+            (2, true),
+            (1681, false),
+            (1693, true),
+            (2047, false),
+            (3215031751, false),
+            (3825123056546413051, false),
+            (18446744073709551557, true),
+            (u64::MAX as u128, false),
+            (u64::MAX as u128 + 1, false),
+            (18446744073709551629, true),
+            (618970019642690137449562111, true),
+            (2305843009213693951 * 18446744073709551557, false),
+            (18446744073709551557 * 18446744073709551557, false),
+            (170141183460469231731687303715884105727, true),
+            (340282366920938463463374607431768211297, true),
+            (u128::MAX, false),
+        ];
+
+        for (input, expected_output) in test_cases {
+            assert_eq!(is_prime(input), expected_output, "is_prime({})", input);
+        }
+
+        let is_prime_by_trial_division = |n: u128| -> bool {
+            return n > 1 && (2..=int_sqrt(n)).all(|i: u128| n % i != 0);
+        };
+
+        for n in 0..100000 {
+            assert_eq!(
+                is_prime(n),
+                is_prime_by_trial_division(n),
+                "is_prime({})",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_strong_lucas_probable_prime() {
+        let pseudoprimes: Vec<u128> = vec![
+            5459, 5777, 10877, 16109, 18971, 22499, 24569, 25199, 40309, 58519, 75077, 97439,
+        ];
+
+        for n in (1601..100000).filter(|&n: &u128| (2..40).all(|p: u128| n % p != 0)) {
+            let is_prime_by_trial_division: bool = (2..=int_sqrt(n)).all(|i: u128| n % i != 0);
+
+            assert_eq!(
+                is_strong_lucas_probable_prime(n),
+                is_prime_by_trial_division || pseudoprimes.contains(&n),
+                "is_strong_lucas_probable_prime({})",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        let test_cases: Vec<(u128, u128, u128, u128)> = vec![
+            (0, 0, 7, 1),
+            (2, 64, u64::MAX as u128, 1),
+            (
+                3,
+                170141183460469231731687303715884105726,
+                170141183460469231731687303715884105727,
+                1,
+            ),
+            (2, 128, u128::MAX, 1),
+            (u128::MAX - 1, 3, u128::MAX, u128::MAX - 1),
+            (
+                10,
+                20,
+                340282366920938463463374607431768211297,
+                100000000000000000000,
+            ),
+        ];
+
+        for (base, exponent, m, expected_output) in test_cases {
+            assert_eq!(pow_mod(base, exponent, m), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_prime_factors_of_n_with_pollard_rho_as_hashmap() {
+        let test_cases: Vec<(u128, Vec<(u128, u128)>)> = vec![
+            (2, vec![(2, 1)]),
+            (1024, vec![(2, 10)]),
+            (997 * 997, vec![(997, 2)]),
+            (1009 * 1009 * 1013, vec![(1009, 2), (1013, 1)]),
+            (
+                u64::MAX as u128,
+                vec![
+                    (3, 1),
+                    (5, 1),
+                    (17, 1),
+                    (257, 1),
+                    (641, 1),
+                    (65537, 1),
+                    (6700417, 1),
+                ],
+            ),
+            (
+                u128::MAX,
+                vec![
+                    (3, 1),
+                    (5, 1),
+                    (17, 1),
+                    (257, 1),
+                    (641, 1),
+                    (65537, 1),
+                    (274177, 1),
+                    (6700417, 1),
+                    (67280421310721, 1),
+                ],
+            ),
+            (
+                2147483647 * 2147483647 * 2305843009213693951,
+                vec![(2147483647, 2), (2305843009213693951, 1)],
+            ),
+            (
+                1000000007 * 998244353 * 1000000009 * 999999937,
+                vec![
+                    (1000000007, 1),
+                    (998244353, 1),
+                    (1000000009, 1),
+                    (999999937, 1),
+                ],
+            ),
+            (
+                170141183460469231731687303715884105727,
+                vec![(170141183460469231731687303715884105727, 1)],
+            ),
+        ];
+
+        for (input, expected_output) in test_cases {
+            assert_eq!(
+                prime_factors_of_n_with_pollard_rho_as_hashmap(input),
+                HashMap::from_iter(expected_output),
+                "factors of {}",
+                input
+            );
+        }
+
+        for n in 2..100000 {
+            let factors: HashMap<u128, u128> = prime_factors_of_n_with_pollard_rho_as_hashmap(n);
+
+            assert!(factors.keys().all(|&prime: &u128| is_prime(prime)));
+            assert_eq!(construct_number_from_prime_factor_hashmap(factors), n);
+        }
+    }
+
+    #[test]
+    fn test_prime_table() {
+        for limit in [0, 1, 2, 3, 10, 97, 100, 1000] {
+            let table: PrimeTable = PrimeTable::new(limit);
+            let primes: Vec<u128> = sieve_of_eratosthenes(limit);
+
+            assert_eq!(table.limit(), limit);
+            assert_eq!(table.primes(), primes.as_slice());
+            assert_eq!(table.iter().collect::<Vec<u128>>(), primes);
+
+            for n in 0..=limit {
+                assert_eq!(table.is_prime(n), is_prime(n), "is_prime({})", n);
+                assert_eq!(
+                    table.pi(n),
+                    primes.iter().filter(|&&prime| prime <= n).count()
+                );
+            }
+
+            for (index, &prime) in primes.iter().enumerate() {
+                assert_eq!(table.nth(index + 1), Some(prime));
+            }
+            assert_eq!(table.nth(primes.len() + 1), None);
+
+            for n in 0..20000 {
+                assert_eq!(
+                    table.factorize(n),
+                    prime_factors_of_n_with_pollard_rho_as_hashmap(n),
+                    "factorize({}) with limit {}",
+                    n,
+                    limit
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_smallest_prime_factor_sieve() {
+        for limit in [0, 1, 2, 3, 4, 10, 97, 100, 100000] {
+            let sieve: SmallestPrimeFactorSieve = SmallestPrimeFactorSieve::new(limit);
+
+            assert_eq!(sieve.limit(), limit);
+            assert_eq!(sieve.primes(), sieve_of_eratosthenes(limit).as_slice());
+
+            for n in 0..=limit {
+                let factors: HashMap<u128, u128> = sieve.factorize(n);
+
+                assert_eq!(factors, prime_factors_of_n_with_pollard_rho_as_hashmap(n));
+                assert_eq!(
+                    sieve.smallest_prime_factor(n),
+                    factors.keys().min().copied().unwrap_or(0)
+                );
+                assert_eq!(sieve.sum_of_all_divisors(n), sum_of_all_divisors(n));
+            }
+        }
+    }
+
+    #[test]
+    fn test_segmented_sieve() {
+        let test_cases: Vec<(u128, u128)> = vec![
+            (0, 0),
+            (0, 2),
+            (2, 2),
+            (4, 4),
+            (0, 100000),
+            (SEGMENT_SIZE - 10, 3 * SEGMENT_SIZE + 10),
+            (99990, 100000),
+        ];
+
+        for (low, high) in test_cases {
+            let expected_output: Vec<u128> = sieve_of_eratosthenes(high)
+                .into_iter()
+                .filter(|&prime: &u128| prime >= low)
+                .collect();
+
+            assert_eq!(segmented_sieve(low, high), expected_output);
+        }
+
+        let (low, high): (u128, u128) = (1_000_000_000_000, 1_000_000_050_000);
+        assert_eq!(
+            segmented_sieve(low, high),
+            (low..=high)
+                .filter(|&n: &u128| is_prime(n))
+                .collect::<Vec<u128>>()
+        );
+    }
+
     #[test]
     fn test_int_sqrt() {
         let test_cases: Vec<(u128, u128)> = vec![
@@ -2001,14 +3419,14 @@ mod tests {
             (3, 1),
             (4, 2),
             (30, 5),
-            (std::u128::MAX, std::u64::MAX as u128),
-            (std::u128::MAX - 1, std::u64::MAX as u128),
+            (u128::MAX, u64::MAX as u128),
+            (u128::MAX - 1, u64::MAX as u128),
             (
                 340282366920938463389587631136930004996,
                 18446744073709551614,
             ),
-            (std::u64::MAX as u128 + 1, std::u32::MAX as u128 + 1),
-            (std::u32::MAX as u128 + 1, std::u16::MAX as u128 + 1),
+            (u64::MAX as u128 + 1, u32::MAX as u128 + 1),
+            (u32::MAX as u128 + 1, u16::MAX as u128 + 1),
         ];
 
         for (input, expected_output) in test_cases {
@@ -0,0 +1,382 @@
+use crate::allocation::{self, AllocationStats};
+use crate::answer::Answer;
+use crate::report::{self, Record, Status};
+use crate::timing::{self, Measurement, Sampling};
+
+/// A named input of a problem, like the upper limit of a search.
+///
+/// The default is the value given in the problem statement. Values outside `min..=max`, or even
+/// values of a parameter that must be odd, are rejected before any solution is run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub default: u128,
+    pub description: &'static str,
+    /// Smallest value the solutions accept.
+    pub min: u128,
+    /// Largest value the solutions accept, usually the largest one whose answer fits in a `u128`.
+    pub max: u128,
+    /// Whether the value must be odd.
+    pub is_odd: bool,
+    /// Whether the parameter is the size of the input, which the `complexity` command can vary.
+    pub is_scalable: bool,
+}
+
+impl Parameter {
+    /// Returns a parameter accepting any value, which can be restricted with [`Parameter::range`]
+    /// and [`Parameter::odd`], and marked as the size of the input with [`Parameter::scalable`].
+    pub fn new(name: &'static str, default: u128, description: &'static str) -> Parameter {
+        return Parameter {
+            name,
+            default,
+            description,
+            min: 0,
+            max: u128::MAX,
+            is_odd: false,
+            is_scalable: false,
+        };
+    }
+
+    /// Returns the parameter accepting only the values from `min` to `max`, both included.
+    pub fn range(mut self, min: u128, max: u128) -> Parameter {
+        self.min = min;
+        self.max = max;
+
+        return self;
+    }
+
+    /// Returns the parameter accepting only odd values.
+    pub fn odd(mut self) -> Parameter {
+        self.is_odd = true;
+
+        return self;
+    }
+
+    /// Returns the parameter marked as the size of the input, so that the `complexity` command
+    /// can time the solutions at smaller values of it.
+    ///
+    /// Parameters like the number of adjacent digits in a fixed grid are not sizes, and timing the
+    /// solutions over them says nothing about their complexity.
+    pub fn scalable(mut self) -> Parameter {
+        self.is_scalable = true;
+
+        return self;
+    }
+
+    /// Checks that the solutions accept `value` for this parameter.
+    ///
+    /// ### Returns
+    ///
+    /// * `Result<(), String>` - A message describing why `value` is not accepted, if it is not.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::problem::Parameter;
+    ///
+    /// let parameter: Parameter = Parameter::new("side_length", 1001, "Side length").range(1, 9999).odd();
+    ///
+    /// assert_eq!(parameter.check(1001), Ok(()));
+    /// assert_eq!(parameter.check(1), Ok(()));
+    ///
+    /// assert_eq!(parameter.check(0), Err(String::from("`side_length` must be at least 1, got 0")));
+    /// assert_eq!(parameter.check(10001), Err(String::from("`side_length` must be at most 9999, got 10001")));
+    /// assert_eq!(parameter.check(1000), Err(String::from("`side_length` must be odd, got 1000")));
+    /// ```
+    pub fn check(&self, value: u128) -> Result<(), String> {
+        if value < self.min {
+            return Err(format!(
+                "`{}` must be at least {}, got {}",
+                self.name, self.min, value
+            ));
+        }
+
+        if value > self.max {
+            return Err(format!(
+                "`{}` must be at most {}, got {}",
+                self.name, self.max, value
+            ));
+        }
+
+        if self.is_odd && value % 2 == 0 {
+            return Err(format!("`{}` must be odd, got {}", self.name, value));
+        }
+
+        return Ok(());
+    }
+}
+
+/// Values of the parameters of a problem, passed to every solution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: Vec<(&'static str, u128)>,
+}
+
+impl Params {
+    /// Returns the value of the parameter `name`.
+    ///
+    /// ### Panics
+    ///
+    /// When the problem did not declare a parameter called `name`.
+    pub fn get(&self, name: &str) -> u128 {
+        return match self.values.iter().find(|(key, _)| *key == name) {
+            Some((_, value)) => *value,
+            None => panic!("Parameter `{}` is not declared.", name),
+        };
+    }
+
+    /// Returns the names and values of all the parameters, in the order they were declared.
+    pub fn values(&self) -> &[(&'static str, u128)] {
+        return &self.values;
+    }
+}
+
+/// A single named way of solving a [`Problem`].
+///
+/// `func` runs the solution with the given parameters and returns the answer.
+pub struct Solution {
+    pub name: &'static str,
+    pub func: fn(&Params) -> Answer,
+}
+
+impl Solution {
+    pub fn new(name: &'static str, func: fn(&Params) -> Answer) -> Solution {
+        return Solution { name, func };
+    }
+
+    /// Runs the solution `sampling.warm_up` times without timing it, then `sampling.repeats`
+    /// times timing every run.
+    ///
+    /// ### Returns
+    ///
+    /// * `(Answer, Measurement, Option<AllocationStats>)` - The answer of the last run, the
+    ///   statistics of the timed runs and the allocations made by the last run, if they were
+    ///   counted.
+    pub fn run(
+        &self,
+        params: &Params,
+        sampling: &Sampling,
+    ) -> (Answer, Measurement, Option<AllocationStats>) {
+        let ((answer, allocations), samples) =
+            timing::sample(sampling.warm_up, sampling.repeats, || {
+                return allocation::measure(|| (self.func)(params));
+            });
+
+        return (answer, Measurement::new(self.name, samples), allocations);
+    }
+}
+
+/// A solved Project Euler problem.
+///
+/// Every problem module exposes a unit struct implementing this trait, which is then listed in
+/// [`PROBLEMS`](crate::problems::PROBLEMS).
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::problems;
+///
+/// let problem = problems::get(3).unwrap();
+///
+/// assert_eq!(problem.number(), 3);
+/// assert_eq!(problem.title(), "Largest Prime Factor");
+/// assert_eq!(problem.url(), "https://projecteuler.net/problem=3");
+/// assert_eq!(problem.solutions()[0].name, "solution_1");
+/// assert_eq!(problem.parameters()[0].name, "number");
+/// ```
+pub trait Problem: Sync {
+    fn number(&self) -> u32;
+
+    fn title(&self) -> &'static str;
+
+    /// All the solution variants of the problem, in the order they should be run.
+    fn solutions(&self) -> Vec<Solution>;
+
+    /// Parameters the solutions accept. Problems without inputs declare none.
+    fn parameters(&self) -> Vec<Parameter> {
+        return Vec::new();
+    }
+
+    fn url(&self) -> String {
+        return format!("https://projecteuler.net/problem={}", self.number());
+    }
+
+    /// Returns the parameters of the problem with their defaults replaced by `overrides`.
+    ///
+    /// Overrides of parameters the problem does not declare are ignored.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::problems;
+    ///
+    /// let problem = problems::get(14).unwrap();
+    ///
+    /// assert_eq!(problem.params(&[]).get("limit"), 1_000_000);
+    ///
+    /// let overrides = vec![(String::from("limit"), 10), (String::from("other"), 1)];
+    /// assert_eq!(problem.params(&overrides).get("limit"), 10);
+    /// ```
+    fn params(&self, overrides: &[(String, u128)]) -> Params {
+        let values: Vec<(&'static str, u128)> = self
+            .parameters()
+            .iter()
+            .map(|parameter: &Parameter| {
+                let value: u128 = overrides
+                    .iter()
+                    .rev()
+                    .find(|(name, _)| name == parameter.name)
+                    .map_or(parameter.default, |&(_, value)| value);
+
+                return (parameter.name, value);
+            })
+            .collect();
+
+        return Params { values };
+    }
+
+    /// Checks constraints between the values of several parameters, which cannot be declared on a
+    /// single [`Parameter`]. Called by [`Problem::validate`] once every value is in its range.
+    fn check_params(&self, _params: &Params) -> Result<(), String> {
+        return Ok(());
+    }
+
+    /// Checks that the solutions accept `params`, which is every value within the constraints of
+    /// its [`Parameter`] and then [`Problem::check_params`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::problems;
+    ///
+    /// let problem = problems::get(24).unwrap();
+    ///
+    /// assert!(problem.validate(&problem.params(&[])).is_ok());
+    /// assert!(problem.validate(&problem.params(&[(String::from("position"), 0)])).is_err());
+    /// assert!(problem.validate(&problem.params(&[(String::from("digit"), 3)])).is_err());
+    /// ```
+    fn validate(&self, params: &Params) -> Result<(), String> {
+        for parameter in self.parameters() {
+            parameter.check(params.get(parameter.name))?;
+        }
+
+        return self.check_params(params);
+    }
+
+    /// Returns the parameter the `complexity` command varies, which is the one called `scale`, or
+    /// the first [scalable](Parameter::scalable) one when `scale` is `None`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::problems;
+    ///
+    /// assert_eq!(problems::get(14).unwrap().scale_parameter(None).unwrap().name, "limit");
+    /// assert_eq!(problems::get(16).unwrap().scale_parameter(None).unwrap().name, "power");
+    /// assert!(problems::get(11).unwrap().scale_parameter(None).is_err());
+    /// assert!(problems::get(16).unwrap().scale_parameter(Some("base")).is_err());
+    /// assert!(problems::get(16).unwrap().scale_parameter(Some("size")).is_err());
+    /// ```
+    fn scale_parameter(&self, scale: Option<&str>) -> Result<Parameter, String> {
+        let parameters: Vec<Parameter> = self.parameters();
+
+        return match scale {
+            Some(scale) => match parameters.into_iter().find(|parameter| parameter.name == scale) {
+                Some(parameter) if parameter.is_scalable => Ok(parameter),
+                Some(_) => Err(format!(
+                    "parameter `{}` of problem {} is not the size of the input and cannot be varied",
+                    scale,
+                    self.number()
+                )),
+                None => Err(format!(
+                    "problem {} has no parameter `{}`",
+                    self.number(),
+                    scale
+                )),
+            },
+            None => parameters
+                .into_iter()
+                .find(|parameter| parameter.is_scalable)
+                .ok_or_else(|| format!("problem {} has no parameters to vary", self.number())),
+        };
+    }
+
+    /// Returns the solutions called `variants`, or every solution when `variants` is empty, in
+    /// the order they are declared in.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::problems;
+    ///
+    /// let problem = problems::get(3).unwrap();
+    ///
+    /// assert_eq!(problem.selected_solutions(&[]).len(), 3);
+    /// assert_eq!(problem.selected_solutions(&[String::from("solution_2")])[0].name, "solution_2");
+    /// ```
+    fn selected_solutions(&self, variants: &[String]) -> Vec<Solution> {
+        return self
+            .solutions()
+            .into_iter()
+            .filter(|solution: &Solution| {
+                return variants.is_empty() || variants.iter().any(|name| name == solution.name);
+            })
+            .collect();
+    }
+
+    /// Runs the solutions called `variants`, or every solution when `variants` is empty, and
+    /// returns the answers with the time taken, which is the median of the timed runs.
+    ///
+    /// The solutions are run in the order they are declared in, whatever the order of `variants`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::problems;
+    /// use project_euler::timing::Sampling;
+    ///
+    /// let problem = problems::get(3).unwrap();
+    /// let params = problem.params(&[]);
+    ///
+    /// assert_eq!(problem.run(&params, &[], &Sampling::ONCE).len(), 3);
+    ///
+    /// let sampling: Sampling = Sampling { warm_up: 1, repeats: 5 };
+    /// let records = problem.run(&params, &[String::from("solution_2")], &sampling);
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(records[0].solution, "solution_2");
+    ///
+    /// let measurement = records[0].measurement.as_ref().unwrap();
+    /// assert_eq!(measurement.samples.len(), 5);
+    /// assert_eq!(measurement.median, records[0].elapsed);
+    /// ```
+    fn run(&self, params: &Params, variants: &[String], sampling: &Sampling) -> Vec<Record> {
+        return self
+            .selected_solutions(variants)
+            .iter()
+            .map(|solution: &Solution| {
+                let (answer, measurement, allocations) = solution.run(params, sampling);
+
+                return Record {
+                    problem: self.number(),
+                    solution: solution.name,
+                    params: params.clone(),
+                    answer,
+                    elapsed: measurement.median,
+                    status: Status::Solved,
+                    measurement: Some(measurement),
+                    allocations,
+                };
+            })
+            .collect();
+    }
+
+    /// Runs the solutions like [`Problem::run`] and prints the answers with the time taken.
+    ///
+    /// The answers and timings are also returned as [`Record`]s.
+    fn solve(&self, params: &Params, variants: &[String], sampling: &Sampling) -> Vec<Record> {
+        let records: Vec<Record> = self.run(params, variants, sampling);
+        report::print_records(&records);
+
+        return records;
+    }
+}
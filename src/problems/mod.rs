@@ -1,3 +1,5 @@
+use crate::problem::Problem;
+
 pub mod problem_1;
 pub mod problem_10;
 pub mod problem_11;
@@ -29,3 +31,86 @@ pub mod problem_6;
 pub mod problem_7;
 pub mod problem_8;
 pub mod problem_9;
+
+/// Registry of all the solved problems, in ascending order of problem number.
+pub static PROBLEMS: &[&dyn Problem] = &[
+    &problem_1::Problem1,
+    &problem_2::Problem2,
+    &problem_3::Problem3,
+    &problem_4::Problem4,
+    &problem_5::Problem5,
+    &problem_6::Problem6,
+    &problem_7::Problem7,
+    &problem_8::Problem8,
+    &problem_9::Problem9,
+    &problem_10::Problem10,
+    &problem_11::Problem11,
+    &problem_12::Problem12,
+    &problem_13::Problem13,
+    &problem_14::Problem14,
+    &problem_15::Problem15,
+    &problem_16::Problem16,
+    &problem_17::Problem17,
+    &problem_18::Problem18,
+    &problem_19::Problem19,
+    &problem_20::Problem20,
+    &problem_21::Problem21,
+    &problem_22::Problem22,
+    &problem_23::Problem23,
+    &problem_24::Problem24,
+    &problem_25::Problem25,
+    &problem_26::Problem26,
+    &problem_27::Problem27,
+    &problem_28::Problem28,
+    &problem_29::Problem29,
+    &problem_30::Problem30,
+    &problem_31::Problem31,
+];
+
+/// Returns the solved problem with the given number, if any.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::problems::get as f;
+///
+/// assert_eq!(f(1).unwrap().number(), 1);
+/// assert_eq!(f(31).unwrap().number(), 31);
+/// assert!(f(0).is_none());
+/// assert!(f(1000).is_none());
+/// ```
+pub fn get(number: u32) -> Option<&'static dyn Problem> {
+    return PROBLEMS
+        .iter()
+        .find(|problem| problem.number() == number)
+        .copied();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timing::{self, Sampling};
+
+    #[test]
+    fn test_solution_variants_agree() {
+        for problem in PROBLEMS
+            .iter()
+            .filter(|problem| problem.solutions().len() > 1)
+        {
+            let results: Vec<(&str, String)> = problem
+                .run(&problem.params(&[]), &[], &Sampling::ONCE)
+                .iter()
+                .map(|record| (record.solution, record.answer.to_string()))
+                .collect();
+
+            timing::assert_agreement(&results);
+        }
+    }
+
+    #[test]
+    fn test_default_params_are_valid() {
+        for problem in PROBLEMS {
+            assert_eq!(problem.validate(&problem.params(&[])), Ok(()));
+        }
+    }
+}
@@ -1,10 +1,12 @@
 // https://projecteuler.net/problem=1
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::problem::{Parameter, Problem, Solution};
+
+const MULTIPLES_OF: [u128; 2] = [3, 5];
 
 #[allow(dead_code)]
-fn solution_1(limit: u128, multiples_of: Vec<u128>) {
+fn solution_1(limit: u128, multiples_of: Vec<u128>) -> Answer {
     let mut ans: u128 = 0;
 
     for i in 0..limit {
@@ -15,13 +17,29 @@ fn solution_1(limit: u128, multiples_of: Vec<u128>) {
             }
         }
     }
-    println!("Answer : {}", ans);
+    return Answer::Integer(ans);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let limit: u128 = 1000;
-    let multiples_of: Vec<u128> = vec![3, 5];
+pub struct Problem1;
 
-    time_solutions!(solution_1(limit, multiples_of));
+impl Problem for Problem1 {
+    fn number(&self) -> u32 {
+        return 1;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Multiples of 3 or 5";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![
+            Parameter::new("limit", 1000, "Sum the multiples below this number").scalable(),
+        ];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("limit"), MULTIPLES_OF.to_vec())
+        })];
+    }
 }
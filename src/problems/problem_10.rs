@@ -1,17 +1,37 @@
 // https://projecteuler.net/problem=10
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(limit: u128) {
+fn solution_1(limit: u128) -> Answer {
     let primes_under_limit: Vec<u128> = maths::sieve_of_eratosthenes(limit - 1);
-    println!("Answer : {}", primes_under_limit.iter().sum::<u128>());
+    return Answer::Integer(primes_under_limit.iter().sum::<u128>());
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let limit: u128 = 2_000_000;
+pub struct Problem10;
+
+impl Problem for Problem10 {
+    fn number(&self) -> u32 {
+        return 10;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Summation of Primes";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![
+            Parameter::new("limit", 2_000_000, "Sum the primes below this number")
+                .range(1, u128::MAX)
+                .scalable(),
+        ];
+    }
 
-    time_solutions!(solution_1(limit));
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("limit"))
+        })];
+    }
 }
@@ -1,25 +1,38 @@
 // https://projecteuler.net/problem=12
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1() {
+fn solution_1() -> Answer {
     let mut n: u128 = 1;
 
-    while n < maths::int_sqrt(std::u128::MAX) {
+    while n < maths::int_sqrt(u128::MAX) {
         let triangle_num: u128 = n * (n + 1) / 2;
 
         if maths::num_divisors(triangle_num) > 500 {
-            println!("Answer : {}", triangle_num);
-            return;
+            return Answer::Integer(triangle_num);
         }
 
         n += 1;
     }
+
+    return Answer::Integer(0);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    time_solutions!(solution_1());
+pub struct Problem12;
+
+impl Problem for Problem12 {
+    fn number(&self) -> u32 {
+        return 12;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Highly Divisible Triangular Number";
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |_| solution_1())];
+    }
 }
@@ -1,11 +1,12 @@
 // https://projecteuler.net/problem=14
 
-use crate::time_solutions;
+use crate::answer::Answer;
+use crate::problem::{Parameter, Problem, Solution};
 #[allow(unused_imports)]
 use std::collections::HashMap;
 
 #[allow(dead_code)]
-fn solution_1(limit: u128) {
+fn solution_1(limit: u128) -> Answer {
     // uses hashmap to store the sequence_lengths already created
     fn helper(map: &mut HashMap<u128, u128>, number: u128, limit: u128) -> u128 {
         #[allow(unused_assignments)]
@@ -56,13 +57,33 @@ fn solution_1(limit: u128) {
         number += 1;
     }
 
-    println!("Using Hashmap");
-    println!("Answer : {}", ans);
+    return Answer::Integer(ans);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let limit: u128 = 1_000_000;
+pub struct Problem14;
+
+impl Problem for Problem14 {
+    fn number(&self) -> u32 {
+        return 14;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Longest Collatz Sequence";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![
+            // Every sequence starting below this path record stays below 6.5 * 10^37, so
+            // `3 * number + 1` never overflows.
+            Parameter::new("limit", 1_000_000, "Starting numbers are below this number")
+                .range(0, 1_980_976_057_694_848_447)
+                .scalable(),
+        ];
+    }
 
-    time_solutions!(solution_1(limit));
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("limit"))
+        })];
+    }
 }
@@ -1,17 +1,36 @@
 // https://projecteuler.net/problem=15
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(n: u128) -> () {
+fn solution_1(n: u128) -> Answer {
     let ans: u128 =
         maths::construct_number_from_prime_factor_hashmap(maths::combinations(2 * n, n));
-    println!("Answer : {}", ans);
+    return Answer::Integer(ans);
 }
 
-#[allow(dead_code)]
-pub fn solve() -> () {
-    let grid_side: u128 = 20;
-    time_solutions!(solution_1(grid_side));
+pub struct Problem15;
+
+impl Problem for Problem15 {
+    fn number(&self) -> u32 {
+        return 15;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Lattice Paths";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![Parameter::new("grid_side", 20, "Side length of the grid")
+            .range(0, 65)
+            .scalable()];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("grid_side"))
+        })];
+    }
 }
@@ -1,24 +1,41 @@
 // https://projecteuler.net/problem=16
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(power: u128, base: u128) {
+fn solution_1(power: u128, base: u128) -> Answer {
     let power_vec: Vec<u8> = maths::u128_to_vecu8(power);
     let base_vec: Vec<u8> = maths::u128_to_vecu8(base);
 
     let ans_vec: Vec<u8> = maths::get_power_of_a_number(base_vec, power_vec);
-    println!("Answer : {:?}", ans_vec);
-
     let ans: u128 = ans_vec.iter().map(|&x| x as u128).sum::<u128>();
 
-    println!("Answer : {}", ans);
+    return Answer::Integer(ans);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let base: u128 = 2;
-    let power: u128 = 1000;
-    time_solutions!(solution_1(power, base));
+pub struct Problem16;
+
+impl Problem for Problem16 {
+    fn number(&self) -> u32 {
+        return 16;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Power Digit Sum";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![
+            Parameter::new("base", 2, "Base of the power"),
+            Parameter::new("power", 1000, "Exponent of the power").scalable(),
+        ];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("power"), params.get("base"))
+        })];
+    }
 }
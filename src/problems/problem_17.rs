@@ -1,10 +1,10 @@
 // https://projecteuler.net/problem=17
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::problem::{Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1() {
+fn solution_1() -> Answer {
     let ones_map: [&str; 20] = [
         "",
         "one",
@@ -78,10 +78,21 @@ fn solution_1() {
 
     ans += "onethousand".len();
 
-    println!("Answer : {}", ans);
+    return Answer::from(ans);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    time_solutions!(solution_1());
+pub struct Problem17;
+
+impl Problem for Problem17 {
+    fn number(&self) -> u32 {
+        return 17;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Number Letter Counts";
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |_| solution_1())];
+    }
 }
@@ -1,10 +1,10 @@
 // https://projecteuler.net/problem=19
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::problem::{Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1() {
+fn solution_1() -> Answer {
     let mut day_num: u8 = 1;
     let mut ans: u128 = 0;
 
@@ -23,22 +23,33 @@ fn solution_1() {
             };
 
             for day in 1..=days {
-                if (day == 1) && (year >= 1901) && (year <= 2000) && (day_num == 7) {
+                if (day == 1) && (1901..=2000).contains(&year) && (day_num == 7) {
                     ans += 1;
                 }
 
                 match day_num {
-                    1..=6 => day_num = day_num + 1,
+                    1..=6 => day_num += 1,
                     7 => day_num = 1,
                     _ => unreachable!(),
                 }
             }
         }
     }
-    println!("Answer : {}", ans);
+    return Answer::Integer(ans);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    time_solutions!(solution_1());
+pub struct Problem19;
+
+impl Problem for Problem19 {
+    fn number(&self) -> u32 {
+        return 19;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Counting Sundays";
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |_| solution_1())];
+    }
 }
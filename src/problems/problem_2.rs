@@ -1,10 +1,10 @@
 // https://projecteuler.net/problem=2
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(limit: u128) {
+fn solution_1(limit: u128) -> Answer {
     let mut a: u128 = 1;
     let mut b: u128 = 2;
     let mut c: u128 = a + b;
@@ -19,12 +19,31 @@ fn solution_1(limit: u128) {
         a = b;
         b = c;
     }
-    println!("Answer : {}", ans);
+    return Answer::Integer(ans);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let limit: u128 = 4_000_000;
+pub struct Problem2;
+
+impl Problem for Problem2 {
+    fn number(&self) -> u32 {
+        return 2;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Even Fibonacci Numbers";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![
+            Parameter::new("limit", 4_000_000, "Upper limit of the Fibonacci terms")
+                .range(0, 205_697_230_343_233_228_174_223_751_303_346_572_684)
+                .scalable(),
+        ];
+    }
 
-    time_solutions!(solution_1(limit));
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("limit"))
+        })];
+    }
 }
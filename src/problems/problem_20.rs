@@ -1,10 +1,11 @@
 // https://projecteuler.net/problem=20
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(num: u128) {
+fn solution_1(num: u128) -> Answer {
     let mut ans_vec: Vec<u8> = vec![1];
 
     for num in 1..=num {
@@ -14,11 +15,29 @@ fn solution_1(num: u128) {
 
     let ans: u128 = ans_vec.iter().map(|&x| x as u128).sum::<u128>();
 
-    println!("Answer : {}", ans);
+    return Answer::Integer(ans);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let num: u128 = 100;
-    time_solutions!(solution_1(num));
+pub struct Problem20;
+
+impl Problem for Problem20 {
+    fn number(&self) -> u32 {
+        return 20;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Factorial Digit Sum";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![
+            Parameter::new("num", 100, "Number whose factorial's digits are summed").scalable(),
+        ];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("num"))
+        })];
+    }
 }
@@ -1,10 +1,11 @@
 // https://projecteuler.net/problem=21
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(num: u128) {
+fn solution_1(num: u128) -> Answer {
     let mut ans: u128 = 0;
     let mut amicable_map: Vec<u128> = vec![0; num as usize];
 
@@ -23,11 +24,53 @@ fn solution_1(num: u128) {
         }
     }
 
-    println!("Answer : {}", ans);
+    return Answer::Integer(ans);
 }
 
 #[allow(dead_code)]
-pub fn solve() {
-    let num: u128 = 10000;
-    time_solutions!(solution_1(num));
+fn solution_2(num: u128) -> Answer {
+    let mut ans: u128 = 0;
+    let sieve: maths::SmallestPrimeFactorSieve = maths::SmallestPrimeFactorSieve::new(num);
+    let mut amicable_map: Vec<u128> = vec![0; num as usize];
+
+    for n in 1..=num {
+        amicable_map[(n - 1) as usize] = sieve.sum_of_all_divisors(n) - n;
+    }
+
+    for n1 in 1..=num {
+        let n2: u128 = amicable_map[(n1 - 1) as usize];
+
+        if n2 != n1 && n2 >= 1 && n2 <= num && amicable_map[(n2 - 1) as usize] == n1 {
+            ans += n1;
+        }
+    }
+
+    return Answer::Integer(ans);
+}
+
+pub struct Problem21;
+
+impl Problem for Problem21 {
+    fn number(&self) -> u32 {
+        return 21;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Amicable Numbers";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![
+            Parameter::new("num", 10000, "Sum the amicable numbers up to this number")
+                .range(0, 100_000_000)
+                .scalable(),
+        ];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![
+            Solution::new("solution_1", |params| solution_1(params.get("num"))),
+            Solution::new("solution_2", |params| solution_2(params.get("num"))),
+        ];
+    }
 }
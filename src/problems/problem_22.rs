@@ -1,39 +1,42 @@
 // https://projecteuler.net/problem=22
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
-use std::fs::File;
-use std::io::Read;
+use crate::answer::Answer;
+use crate::data;
+use crate::problem::{Problem, Solution};
 
-#[allow(dead_code)]
-fn solution_1(file_name: &str) {
-    let mut file: File = File::open(file_name).unwrap();
-    let mut content: String = String::new();
+const FILE_NAME: &str = "0022_names.txt";
 
+#[allow(dead_code)]
+fn solution_1(mut names: Vec<String>) -> Answer {
     let mut ans: u128 = 0;
 
-    file.read_to_string(&mut content).unwrap();
-
-    let mut items: Vec<&str> = content.trim().trim_matches('"').split("\",\"").collect();
+    names.sort();
 
-    items.sort();
-
-    for (index, &name) in items.iter().enumerate() {
+    for (index, name) in names.iter().enumerate() {
         ans += ((index + 1) as u128)
             * name
                 .chars()
-                .map(|c| ((c as u8) - ('A' as u8) + 1) as u128)
+                .map(|c| ((c as u8) - b'A' + 1) as u128)
                 .sum::<u128>()
     }
 
-    println!("Answer : {}", ans);
+    return Answer::Integer(ans);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let file_name: &str = "0022_names.txt";
-    let file_path_string: String = format!("files/{}", file_name);
-    let file_path: &str = &file_path_string;
+pub struct Problem22;
 
-    time_solutions!(solution_1(file_path));
+impl Problem for Problem22 {
+    fn number(&self) -> u32 {
+        return 22;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Names Scores";
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |_| {
+            solution_1(data::load(FILE_NAME, data::parse_words))
+        })];
+    }
 }
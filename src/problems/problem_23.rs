@@ -1,17 +1,18 @@
 // https://projecteuler.net/problem=23
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Problem, Solution};
 use std::collections::HashSet;
 
 #[allow(dead_code)]
-fn solution_1() {
+fn solution_1() -> Answer {
     let mut ans: u128 = 0;
     let mut abundant_numbers: HashSet<u128> = HashSet::new();
     let mut abundant_numbers_sums: HashSet<u128> = HashSet::new();
 
     for num in 2..=28123 {
-        let sum_divisors: u128 = maths::sum_of_all_divisors(num as u128) - num;
+        let sum_divisors: u128 = maths::sum_of_all_divisors(num) - num;
 
         if sum_divisors > num {
             abundant_numbers.insert(num);
@@ -32,10 +33,51 @@ fn solution_1() {
         }
     }
 
-    println!("Answer : {}", ans);
+    return Answer::Integer(ans);
 }
 
 #[allow(dead_code)]
-pub fn solve() {
-    time_solutions!(solution_1());
+fn solution_2() -> Answer {
+    let sieve: maths::SmallestPrimeFactorSieve = maths::SmallestPrimeFactorSieve::new(28123);
+
+    let abundant_numbers: Vec<usize> = (12..=28123)
+        .filter(|&num| sieve.sum_of_all_divisors(num) - num > num)
+        .map(|num| num as usize)
+        .collect();
+
+    let mut is_abundant_sum: Vec<bool> = vec![false; 28124];
+
+    for (index, &num1) in abundant_numbers.iter().enumerate() {
+        for &num2 in &abundant_numbers[index..] {
+            if num1 + num2 > 28123 {
+                break;
+            }
+            is_abundant_sum[num1 + num2] = true;
+        }
+    }
+
+    let ans: u128 = (1..=28123)
+        .filter(|&num| !is_abundant_sum[num as usize])
+        .sum();
+
+    return Answer::Integer(ans);
+}
+
+pub struct Problem23;
+
+impl Problem for Problem23 {
+    fn number(&self) -> u32 {
+        return 23;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Non-Abundant Sums";
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![
+            Solution::new("solution_1", |_| solution_1()),
+            Solution::new("solution_2", |_| solution_2()),
+        ];
+    }
 }
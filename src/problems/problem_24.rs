@@ -1,10 +1,11 @@
 // https://projecteuler.net/problem=24
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Params, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(digit: u128, position: u128) {
+fn solution_1(digit: u128, position: u128) -> Answer {
     let mut digit: u128 = digit;
     let mut position: u128 = position - 1;
     let mut available_digits: Vec<u8> = (0..=digit).map(|x: u128| x as u8).collect::<Vec<u8>>();
@@ -26,17 +27,49 @@ fn solution_1(digit: u128, position: u128) {
     }
     let ans_str: String = ans.iter().map(|&x| (x + b'0') as char).collect::<String>();
 
-    println!("{}", ans_str);
-
-    println!(
-        "{:?}",
-        maths::add_two_numbers_as_vec(vec![1, 2], vec![1, 2],)
-    );
+    return Answer::Text(ans_str);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let digit: u128 = 9;
-    let position: u128 = 1_000_000;
-    time_solutions!(solution_1(digit, position));
+pub struct Problem24;
+
+impl Problem for Problem24 {
+    fn number(&self) -> u32 {
+        return 24;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Lexicographic Permutations";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![
+            Parameter::new("digit", 9, "Permute the digits 0 to this digit").range(0, 9),
+            Parameter::new(
+                "position",
+                1_000_000,
+                "Position of the permutation in lexicographic order, at most (digit + 1)!",
+            )
+            .range(1, u128::MAX),
+        ];
+    }
+
+    fn check_params(&self, params: &Params) -> Result<(), String> {
+        let n_permutations: u128 = maths::factorial_as_u128(params.get("digit") + 1);
+
+        if params.get("position") > n_permutations {
+            return Err(format!(
+                "`position` must be at most (digit + 1)! = {}, got {}",
+                n_permutations,
+                params.get("position")
+            ));
+        }
+
+        return Ok(());
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("digit"), params.get("position"))
+        })];
+    }
 }
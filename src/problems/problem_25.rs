@@ -1,10 +1,11 @@
 // https://projecteuler.net/problem=25
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(num_digits: u128) {
+fn solution_1(num_digits: u128) -> Answer {
     let mut fib_n_minus_2: Vec<u8> = vec![1];
     let mut fib_n_minus_1: Vec<u8> = vec![1];
     let mut fib_n: Vec<u8>;
@@ -12,7 +13,7 @@ fn solution_1(num_digits: u128) {
     let mut index: u128 = 2;
 
     loop {
-        fib_n = maths::add_two_numbers_as_vec(fib_n_minus_1.clone(), fib_n_minus_2.clone(),);
+        fib_n = maths::add_two_numbers_as_vec(fib_n_minus_1.clone(), fib_n_minus_2.clone());
 
         index += 1;
 
@@ -23,11 +24,29 @@ fn solution_1(num_digits: u128) {
         fib_n_minus_2 = fib_n_minus_1.clone();
         fib_n_minus_1 = fib_n.clone();
     }
-    println!("{}", index);
+    return Answer::Integer(index);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let num_digits: u128 = 1000;
-    time_solutions!(solution_1(num_digits));
+pub struct Problem25;
+
+impl Problem for Problem25 {
+    fn number(&self) -> u32 {
+        return 25;
+    }
+
+    fn title(&self) -> &'static str {
+        return "1000-digit Fibonacci Number";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![
+            Parameter::new("num_digits", 1000, "Number of digits of the Fibonacci term").scalable(),
+        ];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("num_digits"))
+        })];
+    }
 }
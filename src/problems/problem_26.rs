@@ -1,10 +1,11 @@
 // https://projecteuler.net/problem=26
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(limit: u128) {
+fn solution_1(limit: u128) -> Answer {
     let mut max_digits: usize = 0;
     let mut num_with_max_digits: u128 = 2;
 
@@ -17,14 +18,32 @@ fn solution_1(limit: u128) {
         }
     }
 
-    println!(
-        "Number : {} has the most number of repeating digits : {}",
-        num_with_max_digits, max_digits
-    );
+    return Answer::Tuple(vec![
+        ("number", num_with_max_digits as i128),
+        ("repeating digits", max_digits as i128),
+    ]);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let limit: u128 = 1000;
-    time_solutions!(solution_1(limit));
+pub struct Problem26;
+
+impl Problem for Problem26 {
+    fn number(&self) -> u32 {
+        return 26;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Reciprocal Cycles";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![
+            Parameter::new("limit", 1000, "Consider denominators up to this number").scalable(),
+        ];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("limit"))
+        })];
+    }
 }
@@ -1,7 +1,8 @@
 // https://projecteuler.net/problem=27
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Problem, Solution};
 
 fn get_prime_count(a: i128, b: i128) -> u128 {
     let mut n: i128 = 0;
@@ -36,7 +37,7 @@ fn get_prime_count(a: i128, b: i128) -> u128 {
 }
 
 #[allow(dead_code)]
-fn solution_1() {
+fn solution_1() -> Answer {
     let mut max_consecutive_primes: u128 = 0;
     let mut a_for_max_consecutive_primes: i128 = 0;
     let mut b_for_max_consecutive_primes: i128 = 0;
@@ -53,16 +54,29 @@ fn solution_1() {
         }
     }
 
-    println!(
-        "n : {}, a : {}, b : {}, a * b : {}",
-        max_consecutive_primes,
-        a_for_max_consecutive_primes,
-        b_for_max_consecutive_primes,
-        a_for_max_consecutive_primes * b_for_max_consecutive_primes
-    );
+    return Answer::Tuple(vec![
+        ("n", max_consecutive_primes as i128),
+        ("a", a_for_max_consecutive_primes),
+        ("b", b_for_max_consecutive_primes),
+        (
+            "a * b",
+            a_for_max_consecutive_primes * b_for_max_consecutive_primes,
+        ),
+    ]);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    time_solutions!(solution_1());
+pub struct Problem27;
+
+impl Problem for Problem27 {
+    fn number(&self) -> u32 {
+        return 27;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Quadratic Primes";
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |_| solution_1())];
+    }
 }
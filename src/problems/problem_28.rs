@@ -1,10 +1,11 @@
 // https://projecteuler.net/problem=28
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(side_length: u128) {
+fn solution_1(side_length: u128) -> Answer {
     let matrix: Vec<Vec<u128>> = maths::create_spiral_matrix(side_length);
 
     let mut diagonal_sum: u128 = 0;
@@ -14,11 +15,34 @@ fn solution_1(side_length: u128) {
         diagonal_sum += matrix[i][side_length as usize - i - 1];
     }
 
-    println!("{:?}", diagonal_sum - 1);
+    return Answer::Integer(diagonal_sum - 1);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let side_length: u128 = 1001;
-    time_solutions!(solution_1(side_length));
+pub struct Problem28;
+
+impl Problem for Problem28 {
+    fn number(&self) -> u32 {
+        return 28;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Number Spiral Diagonals";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![Parameter::new(
+            "side_length",
+            1001,
+            "Side length of the spiral, must be odd",
+        )
+        .range(3, u128::MAX)
+        .odd()
+        .scalable()];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("side_length"))
+        })];
+    }
 }
@@ -2,23 +2,24 @@
 
 use std::collections::{HashMap, HashSet};
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths::{PrimeTable, SmallestPrimeFactorSieve};
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(limit: u128) {
-    let primes_upto_n: Vec<u128> = maths::primes_upto_n_without_sieve(limit);
+fn solution_1(limit: u128) -> Answer {
+    let table: PrimeTable = PrimeTable::new(limit);
+    let primes_upto_n: &[u128] = table.primes();
 
     let mut tracker_hashset: HashSet<Vec<u128>> = HashSet::new();
 
     for base in 2..=limit {
-        for power in 2..=limit {
-            let prime_factors_base: HashMap<u128, u128> =
-                maths::prime_factors_of_n_with_sieve_as_hashmap(base);
+        let prime_factors_base: HashMap<u128, u128> = table.factorize(base);
 
+        for power in 2..=limit {
             let mut unique_representation: Vec<u128> = Vec::new();
 
-            for prime in &primes_upto_n {
+            for prime in primes_upto_n {
                 if prime_factors_base.contains_key(prime) {
                     unique_representation.push(1);
                 } else {
@@ -26,7 +27,7 @@ fn solution_1(limit: u128) {
                 }
             }
 
-            for prime in &primes_upto_n {
+            for prime in primes_upto_n {
                 let prime_count_in_power: u128 = *prime_factors_base.get(prime).unwrap_or(&0);
 
                 unique_representation.push(prime_count_in_power * power);
@@ -38,11 +39,54 @@ fn solution_1(limit: u128) {
         }
     }
 
-    println!("{}", tracker_hashset.len());
+    return Answer::from(tracker_hashset.len());
 }
 
 #[allow(dead_code)]
-pub fn solve() {
-    let limit: u128 = 100;
-    time_solutions!(solution_1(limit));
+fn solution_2(limit: u128) -> Answer {
+    let sieve: SmallestPrimeFactorSieve = SmallestPrimeFactorSieve::new(limit);
+
+    let mut tracker_hashset: HashSet<Vec<(u128, u128)>> = HashSet::new();
+
+    for base in 2..=limit {
+        let mut prime_factors_base: Vec<(u128, u128)> = sieve.factorize(base).into_iter().collect();
+        prime_factors_base.sort();
+
+        for power in 2..=limit {
+            // base^power is identified by its prime factorization, sorted by prime.
+            tracker_hashset.insert(
+                prime_factors_base
+                    .iter()
+                    .map(|&(prime, count)| (prime, count * power))
+                    .collect(),
+            );
+        }
+    }
+
+    return Answer::from(tracker_hashset.len());
+}
+
+pub struct Problem29;
+
+impl Problem for Problem29 {
+    fn number(&self) -> u32 {
+        return 29;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Distinct Powers";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![Parameter::new("limit", 100, "Largest base and exponent")
+            .range(0, 100_000_000)
+            .scalable()];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![
+            Solution::new("solution_1", |params| solution_1(params.get("limit"))),
+            Solution::new("solution_2", |params| solution_2(params.get("limit"))),
+        ];
+    }
 }
@@ -1,39 +1,74 @@
 // https://projecteuler.net/problem=3
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(number: u128) {
+fn solution_1(number: u128) -> Answer {
     let mut ans: u128 = maths::int_sqrt(number);
 
     while ans > 2 {
         if number % ans == 0 && maths::is_prime(ans) {
-            println!("Answer : {}", ans);
-
-            return;
+            return Answer::Integer(ans);
         }
         ans -= 1;
     }
+
+    return Answer::Integer(number);
 }
 
 #[allow(dead_code)]
-fn solution_2(number: u128) {
+fn solution_2(number: u128) -> Answer {
     let ans: u128 = maths::int_sqrt(number);
 
     let primes: Vec<u128> = maths::sieve_of_eratosthenes(ans);
 
     for prime in primes.iter().rev() {
         if number % *prime == 0 {
-            println!("Answer : {}", prime);
-            return;
+            return Answer::Integer(*prime);
         }
     }
+
+    return Answer::Integer(number);
 }
 
 #[allow(dead_code)]
-pub fn solve() {
-    let number: u128 = 600851475143;
+fn solution_3(number: u128) -> Answer {
+    let ans: u128 = maths::prime_factors_of_n_with_pollard_rho_as_hashmap(number)
+        .into_keys()
+        .max()
+        .unwrap_or(number);
 
-    time_solutions!(solution_1(number), solution_2(number));
+    return Answer::Integer(ans);
+}
+
+pub struct Problem3;
+
+impl Problem for Problem3 {
+    fn number(&self) -> u32 {
+        return 3;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Largest Prime Factor";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![Parameter::new(
+            "number",
+            600851475143,
+            "Number to find the largest prime factor of",
+        )
+        .range(2, u128::MAX)
+        .scalable()];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![
+            Solution::new("solution_1", |params| solution_1(params.get("number"))),
+            Solution::new("solution_2", |params| solution_2(params.get("number"))),
+            Solution::new("solution_3", |params| solution_3(params.get("number"))),
+        ];
+    }
 }
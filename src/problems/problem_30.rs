@@ -1,7 +1,7 @@
 // https://projecteuler.net/problem=30
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
 fn digit_power_sum(num: u128) -> u128 {
@@ -25,7 +25,7 @@ fn digit_power_sum(num: u128) -> u128 {
 }
 
 #[allow(dead_code)]
-fn solution_1(upper_limit: u128) {
+fn solution_1(upper_limit: u128) -> Answer {
     let mut sum_of_satisfying_numbers: u128 = 0;
 
     for num in 2..=upper_limit {
@@ -34,11 +34,35 @@ fn solution_1(upper_limit: u128) {
         }
     }
 
-    println!("Sum : {}", sum_of_satisfying_numbers);
+    return Answer::Integer(sum_of_satisfying_numbers);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let upper_limit: u128 = 6 * 9_u128.pow(5); // https://www.youtube.com/watch?v=yaEhy5X9qcI
-    time_solutions!(solution_1(upper_limit));
+pub struct Problem30;
+
+impl Problem for Problem30 {
+    fn number(&self) -> u32 {
+        return 30;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Digit Fifth Powers";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![
+            // https://www.youtube.com/watch?v=yaEhy5X9qcI
+            Parameter::new(
+                "upper_limit",
+                6 * 9_u128.pow(5),
+                "Search numbers up to this number",
+            )
+            .scalable(),
+        ];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("upper_limit"))
+        })];
+    }
 }
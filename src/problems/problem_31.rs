@@ -1,18 +1,18 @@
 // https://projecteuler.net/problem=31
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::problem::{Parameter, Problem, Solution};
+
+const AVAILABLE_CHUNKS: [u128; 8] = [1, 2, 5, 10, 20, 50, 100, 200];
 
 #[allow(dead_code)]
-fn solution_1(target: u128, available_counts: [u128; 8]) {
+fn solution_1(target: u128, available_counts: [u128; 8]) -> Answer {
     if target == 0 {
-        println!("Count : 1");
-        return;
+        return Answer::Integer(1);
     }
 
-    if available_counts.len() == 0 {
-        println!("Count : 0");
-        return;
+    if available_counts.is_empty() {
+        return Answer::Integer(0);
     }
 
     let mut dp: Vec<u128> = vec![0; (target + 1) as usize];
@@ -23,12 +23,29 @@ fn solution_1(target: u128, available_counts: [u128; 8]) {
             dp[i as usize] += dp[(i - count) as usize];
         }
     }
-    println!("Count : {}", dp.last().unwrap());
+    return Answer::Integer(*dp.last().unwrap());
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let target: u128 = 200;
-    let available_chunks: [u128; 8] = [1, 2, 5, 10, 20, 50, 100, 200];
-    time_solutions!(solution_1(target, available_chunks));
+pub struct Problem31;
+
+impl Problem for Problem31 {
+    fn number(&self) -> u32 {
+        return 31;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Coin Sums";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![Parameter::new("target", 200, "Amount in pence to make")
+            .range(0, 23_024_067)
+            .scalable()];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("target"), AVAILABLE_CHUNKS)
+        })];
+    }
 }
@@ -1,10 +1,11 @@
 // https://projecteuler.net/problem=4
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(n_digit: u32) {
+fn solution_1(n_digit: u32) -> Answer {
     let upper: u128 = u128::pow(10, n_digit) - 1;
     let lower: u128 = u128::pow(10, n_digit - 1);
 
@@ -12,18 +13,37 @@ fn solution_1(n_digit: u32) {
 
     for num1 in (lower..=upper).rev() {
         for num2 in (lower..=upper).rev() {
-            let product: u128 = (num1 * num2) as u128;
+            let product: u128 = num1 * num2;
             if maths::is_palindrome(product) {
                 ans = u128::max(ans, product);
             }
         }
     }
-    println!("Answer : {}", ans)
+    return Answer::Integer(ans);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let n_digit: u32 = 3;
+pub struct Problem4;
+
+impl Problem for Problem4 {
+    fn number(&self) -> u32 {
+        return 4;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Largest Palindrome Product";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![
+            Parameter::new("n_digit", 3, "Number of digits of the two factors")
+                .range(1, 19)
+                .scalable(),
+        ];
+    }
 
-    time_solutions!(solution_1(n_digit));
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("n_digit") as u32)
+        })];
+    }
 }
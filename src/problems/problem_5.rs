@@ -1,23 +1,24 @@
 // https://projecteuler.net/problem=5
 
-use crate::{maths, time_solutions};
-#[allow(unused_imports)]
-use std::{collections::HashMap, time::Instant};
+use crate::answer::Answer;
+use crate::maths::{PrimeTable, SmallestPrimeFactorSieve};
+use crate::problem::{Parameter, Problem, Solution};
+use std::collections::HashMap;
 
 #[allow(dead_code)]
-fn solution_1(limit: u128) {
+fn solution_1(limit: u128) -> Answer {
     let mut primes_map: HashMap<u128, u128> = HashMap::new();
 
     let mut ans: u128 = 1;
 
-    let primes: Vec<u128> = maths::sieve_of_eratosthenes(limit);
+    let table: PrimeTable = PrimeTable::new(limit);
 
-    for i in &primes {
-        primes_map.insert(*i, 0);
+    for i in table.iter() {
+        primes_map.insert(i, 0);
     }
 
     for i in 2..=limit {
-        let temp_map: HashMap<u128, u128> = maths::prime_factors_of_n_with_sieve_as_hashmap(i);
+        let temp_map: HashMap<u128, u128> = table.factorize(i);
         for (&key, &val) in temp_map.iter() {
             primes_map.insert(key, u128::max(val, *primes_map.get(&key).unwrap()));
         }
@@ -27,12 +28,54 @@ fn solution_1(limit: u128) {
         ans *= u128::pow(key, val as u32);
     }
 
-    println!("Answer : {}", ans);
+    return Answer::Integer(ans);
 }
 
 #[allow(dead_code)]
-pub fn solve() {
-    let limit: u128 = 20;
+fn solution_2(limit: u128) -> Answer {
+    let sieve: SmallestPrimeFactorSieve = SmallestPrimeFactorSieve::new(limit);
+    let mut primes_map: HashMap<u128, u128> = HashMap::new();
+
+    for i in 2..=limit {
+        for (prime, power) in sieve.factorize(i) {
+            let max_power: &mut u128 = primes_map.entry(prime).or_insert(0);
+            *max_power = u128::max(*max_power, power);
+        }
+    }
+
+    let ans: u128 = primes_map
+        .iter()
+        .map(|(&prime, &power)| prime.pow(power as u32))
+        .product();
+
+    return Answer::Integer(ans);
+}
 
-    time_solutions!(solution_1(limit));
+pub struct Problem5;
+
+impl Problem for Problem5 {
+    fn number(&self) -> u32 {
+        return 5;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Smallest Multiple";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![Parameter::new(
+            "limit",
+            20,
+            "Find the smallest number divisible by 1 to this number",
+        )
+        .range(1, 88)
+        .scalable()];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![
+            Solution::new("solution_1", |params| solution_1(params.get("limit"))),
+            Solution::new("solution_2", |params| solution_2(params.get("limit"))),
+        ];
+    }
 }
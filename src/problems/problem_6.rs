@@ -1,19 +1,33 @@
 // https://projecteuler.net/problem=6
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(n: u128) {
-    println!(
-        "Answer : {}",
-        u128::pow((n * (n + 1)) / 2, 2) - (n * (n + 1) * (2 * n + 1)) / 6
-    );
+fn solution_1(n: u128) -> Answer {
+    return Answer::Integer(u128::pow((n * (n + 1)) / 2, 2) - (n * (n + 1) * (2 * n + 1)) / 6);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let n: u128 = 100;
+pub struct Problem6;
+
+impl Problem for Problem6 {
+    fn number(&self) -> u32 {
+        return 6;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Sum Square Difference";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![Parameter::new("n", 100, "Use the first n natural numbers")
+            .range(0, 6_074_000_999)
+            .scalable()];
+    }
 
-    time_solutions!(solution_1(n));
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("n"))
+        })];
+    }
 }
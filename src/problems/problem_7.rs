@@ -1,16 +1,34 @@
 // https://projecteuler.net/problem=7
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(n: u128) {
-    println!("Answer : {}", maths::nth_prime(n));
+fn solution_1(n: u128) -> Answer {
+    return Answer::Integer(maths::nth_prime(n));
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let n: u128 = 10001;
+pub struct Problem7;
+
+impl Problem for Problem7 {
+    fn number(&self) -> u32 {
+        return 7;
+    }
+
+    fn title(&self) -> &'static str {
+        return "10001st Prime";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![Parameter::new("n", 10001, "Position of the prime to find")
+            .range(1, u128::MAX)
+            .scalable()];
+    }
 
-    time_solutions!(solution_1(n));
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("n"))
+        })];
+    }
 }
@@ -1,10 +1,13 @@
 // https://projecteuler.net/problem=8
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::data;
+use crate::problem::{Parameter, Problem, Solution};
+
+const FILE_NAME: &str = "0008_number.txt";
 
 #[allow(dead_code)]
-fn solution_1(n: String, size: usize) {
+fn solution_1(n: String, size: usize) -> Answer {
     let ans: u128 = n
         .chars()
         .map(|c: char| c as u8 - b'0')
@@ -18,13 +21,28 @@ fn solution_1(n: String, size: usize) {
         .max()
         .unwrap();
 
-    println!("Answer : {}", ans);
+    return Answer::Integer(ans);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let n: String = String::from("7316717653133062491922511967442657474235534919493496983520312774506326239578318016984801869478851843858615607891129494954595017379583319528532088055111254069874715852386305071569329096329522744304355766896648950445244523161731856403098711121722383113622298934233803081353362766142828064444866452387493035890729629049156044077239071381051585930796086670172427121883998797908792274921901699720888093776657273330010533678812202354218097512545405947522435258490771167055601360483958644670632441572215539753697817977846174064955149290862569321978468622482839722413756570560574902614079729686524145351004748216637048440319989000889524345065854122758866688116427171479924442928230863465674813919123162824586178664583591245665294765456828489128831426076900422421902267105562632111110937054421750694165896040807198403850962455444362981230987879927244284909188845801561660979191338754992005240636899125607176060588611646710940507754100225698315520005593572972571636269561882670428252483600823257530420752963450");
-    let size: usize = 13;
+pub struct Problem8;
+
+impl Problem for Problem8 {
+    fn number(&self) -> u32 {
+        return 8;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Largest Product in a Series";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![Parameter::new("size", 13, "Number of adjacent digits").range(1, 40)];
+    }
 
-    time_solutions!(solution_1(n, size));
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            let number: String = data::load(FILE_NAME, data::parse_number_lines).concat();
+            solution_1(number, params.get("size") as usize)
+        })];
+    }
 }
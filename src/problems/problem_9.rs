@@ -1,10 +1,11 @@
 // https://projecteuler.net/problem=9
 
-#[allow(unused_imports)]
-use crate::{maths, time_solutions};
+use crate::answer::Answer;
+use crate::maths;
+use crate::problem::{Parameter, Problem, Solution};
 
 #[allow(dead_code)]
-fn solution_1(n: u128) {
+fn solution_1(sum: u128) -> Answer {
     /*
     Euclid's Formula
 
@@ -15,7 +16,7 @@ fn solution_1(n: u128) {
     b = 2*m*n
     c = m^2 + n^2
     */
-    let sqrt: u128 = maths::int_sqrt(n) + 1;
+    let sqrt: u128 = maths::int_sqrt(sum) + 1;
 
     for n in 1..=sqrt {
         for m in n + 1..=sqrt {
@@ -23,17 +24,33 @@ fn solution_1(n: u128) {
             let b: u128 = 2 * m * n;
             let c: u128 = m * m + n * n;
 
-            if a + b + c == 1000 {
-                println!("Answer : {}", a * b * c);
-                return;
+            if a + b + c == sum {
+                return Answer::Integer(a * b * c);
             }
         }
     }
+
+    return Answer::Integer(0);
 }
 
-#[allow(dead_code)]
-pub fn solve() {
-    let n: u128 = 1000;
+pub struct Problem9;
 
-    time_solutions!(solution_1(n));
+impl Problem for Problem9 {
+    fn number(&self) -> u32 {
+        return 9;
+    }
+
+    fn title(&self) -> &'static str {
+        return "Special Pythagorean Triplet";
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        return vec![Parameter::new("sum", 1000, "Sum of the Pythagorean triplet").scalable()];
+    }
+
+    fn solutions(&self) -> Vec<Solution> {
+        return vec![Solution::new("solution_1", |params| {
+            solution_1(params.get("sum"))
+        })];
+    }
 }
@@ -0,0 +1,596 @@
+use crate::allocation::AllocationStats;
+use crate::answer::Answer;
+use crate::problem::Params;
+use crate::timing::{self, Measurement};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Number of slowest solutions highlighted in the summary table.
+pub const N_SLOWEST: usize = 3;
+
+/// How the run of a single solution ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The solution returned an answer.
+    Solved,
+    /// The solution was stopped after running longer than the timeout.
+    Timeout,
+    /// The solution ran out of the memory it was allowed to use.
+    OutOfMemory,
+    /// The solution panicked or was killed.
+    Crashed,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Status::Solved => write!(f, "SOLVED"),
+            Status::Timeout => write!(f, "TIMEOUT"),
+            Status::OutOfMemory => write!(f, "OOM"),
+            Status::Crashed => write!(f, "CRASHED"),
+        };
+    }
+}
+
+/// The result of running a single solution of a problem.
+///
+/// `elapsed` is the median of the timed runs of the solution. When `status` is not
+/// [`Status::Solved`], `answer` is an empty [`Answer::Text`], `elapsed` is the time until the
+/// solution was stopped and `measurement` is `None`.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub problem: u32,
+    pub solution: &'static str,
+    /// Values of the parameters the solution was run with.
+    pub params: Params,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    pub status: Status,
+    /// Statistics of the timed runs of the solution.
+    pub measurement: Option<Measurement>,
+    /// Allocations made by the solution, if they were counted.
+    pub allocations: Option<AllocationStats>,
+}
+
+impl Record {
+    /// Returns the answer, or the status when the solution did not return an answer.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::answer::Answer;
+    /// use project_euler::problem::Params;
+    /// use project_euler::report::{Record, Status};
+    /// use std::time::Duration;
+    ///
+    /// let mut record: Record = Record {
+    ///     problem: 3,
+    ///     solution: "solution_1",
+    ///     params: Params::default(),
+    ///     answer: Answer::Integer(6857),
+    ///     elapsed: Duration::ZERO,
+    ///     status: Status::Solved,
+    ///     measurement: None,
+    ///     allocations: None,
+    /// };
+    /// assert_eq!(record.result(), "6857");
+    ///
+    /// record.answer = Answer::Text(String::new());
+    /// record.status = Status::Timeout;
+    /// assert_eq!(record.result(), "TIMEOUT");
+    /// ```
+    pub fn result(&self) -> String {
+        return match self.status {
+            Status::Solved => self.answer.to_string(),
+            status => status.to_string(),
+        };
+    }
+}
+
+/// Output format of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable banners, as printed by [`Problem::solve`](crate::problem::Problem::solve).
+    Text,
+    /// A JSON array with one object per record.
+    Json,
+    /// CSV with a header row and one row per record.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::report::Format;
+    ///
+    /// assert_eq!("text".parse::<Format>(), Ok(Format::Text));
+    /// assert_eq!("JSON".parse::<Format>(), Ok(Format::Json));
+    /// assert_eq!("csv".parse::<Format>(), Ok(Format::Csv));
+    /// assert!("xml".parse::<Format>().is_err());
+    /// ```
+    fn from_str(format: &str) -> Result<Format, String> {
+        return match format.to_lowercase().as_str() {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format `{}`, expected one of json, csv or text",
+                format
+            )),
+        };
+    }
+}
+
+/// Prints the answer, the time taken and the allocations made, if they were counted, of every
+/// record, as [`Problem::solve`] does. The statistics of the timed runs are printed when the
+/// solution was run more than once.
+///
+/// [`Problem::solve`]: crate::problem::Problem::solve
+pub fn print_records(records: &[Record]) {
+    for record in records {
+        println!("-----------------------------------------------------------");
+        println!("Answer : {}", record.result());
+        match &record.measurement {
+            Some(measurement) if measurement.samples.len() > 1 => println!("{}", measurement),
+            _ => println!("{} took {:?}", record.solution, record.elapsed),
+        }
+        if let Some(stats) = record.allocations {
+            println!(
+                "{} allocations, {} bytes allocated, {} bytes peak",
+                stats.allocations, stats.allocated_bytes, stats.peak_bytes
+            );
+        }
+    }
+}
+
+/// Returns a message for every problem whose solutions returned different answers.
+///
+/// Only the solutions that returned an answer are compared, so a problem with a single solved
+/// variant never disagrees.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::answer::Answer;
+/// use project_euler::problem::Params;
+/// use project_euler::report::{disagreements, Record, Status};
+/// use std::time::Duration;
+///
+/// let record = |solution: &'static str, answer: u128| Record {
+///     problem: 3,
+///     solution,
+///     params: Params::default(),
+///     answer: Answer::Integer(answer),
+///     elapsed: Duration::ZERO,
+///     status: Status::Solved,
+///     measurement: None,
+///     allocations: None,
+/// };
+///
+/// assert!(disagreements(&[record("solution_1", 6857), record("solution_2", 6857)]).is_empty());
+/// assert_eq!(
+///     disagreements(&[record("solution_1", 6857), record("solution_2", 1471)]),
+///     vec![String::from(
+///         "Problem 3 : Solutions disagree : solution_1 returned 6857, solution_2 returned 1471."
+///     )]
+/// );
+/// ```
+pub fn disagreements(records: &[Record]) -> Vec<String> {
+    let mut problems: Vec<u32> = records
+        .iter()
+        .map(|record: &Record| record.problem)
+        .collect();
+    problems.dedup();
+
+    return problems
+        .into_iter()
+        .filter_map(|problem: u32| {
+            let results: Vec<(&str, String)> = records
+                .iter()
+                .filter(|record: &&Record| {
+                    return record.problem == problem && record.status == Status::Solved;
+                })
+                .map(|record: &Record| (record.solution, record.answer.to_string()))
+                .collect();
+
+            return timing::check_agreement(&results)
+                .err()
+                .map(|message: String| format!("Problem {} : {}", problem, message));
+        })
+        .collect();
+}
+
+/// Returns a summary table of the given records, followed by the totals.
+///
+/// The [`N_SLOWEST`] slowest solutions are marked with a `*` in the first column and listed again
+/// below the totals.
+///
+/// ### Arguments
+///
+/// * `records` : `&[Record]` - The records to summarise, in the order they were run.
+///
+/// ### Returns
+///
+/// * `String` - The formatted table.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::answer::Answer;
+/// use project_euler::problem::Params;
+/// use project_euler::report::{summary_table, Record, Status};
+/// use std::time::Duration;
+///
+/// let records: Vec<Record> = vec![
+///     Record {
+///         problem: 1,
+///         solution: "solution_1",
+///         params: Params::default(),
+///         answer: Answer::Integer(233168),
+///         elapsed: Duration::from_micros(10),
+///         status: Status::Solved,
+///         measurement: None,
+///         allocations: None,
+///     },
+/// ];
+///
+/// let table: String = summary_table(&records);
+///
+/// assert!(table.contains("solution_1"));
+/// assert!(table.contains("233168"));
+/// assert!(table.contains("Total : 1 solutions of 1 problems"));
+/// ```
+pub fn summary_table(records: &[Record]) -> String {
+    let headers: [&str; 4] = ["Problem", "Solution", "Answer", "Time"];
+
+    let rows: Vec<[String; 4]> = records
+        .iter()
+        .map(|record: &Record| {
+            [
+                record.problem.to_string(),
+                record.solution.to_string(),
+                record.result(),
+                format!("{:.2?}", record.elapsed),
+            ]
+        })
+        .collect();
+
+    let mut widths: [usize; 4] = headers.map(|header: &str| header.len());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = usize::max(*width, cell.len());
+        }
+    }
+
+    let mut slowest: Vec<usize> = (0..records.len()).collect();
+    slowest.sort_by(|&i, &j| records[j].elapsed.cmp(&records[i].elapsed));
+    slowest.truncate(N_SLOWEST);
+
+    let line_width: usize = widths.iter().sum::<usize>() + 3 * widths.len() + 2;
+    let separator: String = "-".repeat(line_width);
+
+    let mut table: String = String::new();
+
+    table.push_str(&format!(
+        "  {:>w0$} | {:<w1$} | {:<w2$} | {:>w3$}\n",
+        headers[0],
+        headers[1],
+        headers[2],
+        headers[3],
+        w0 = widths[0],
+        w1 = widths[1],
+        w2 = widths[2],
+        w3 = widths[3],
+    ));
+    table.push_str(&separator);
+    table.push('\n');
+
+    for (index, row) in rows.iter().enumerate() {
+        let marker: char = if slowest.contains(&index) { '*' } else { ' ' };
+
+        table.push_str(&format!(
+            "{} {:>w0$} | {:<w1$} | {:<w2$} | {:>w3$}\n",
+            marker,
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        ));
+    }
+    table.push_str(&separator);
+    table.push('\n');
+
+    let mut problems: Vec<u32> = records
+        .iter()
+        .map(|record: &Record| record.problem)
+        .collect();
+    problems.dedup();
+
+    let total_elapsed: Duration = records.iter().map(|record: &Record| record.elapsed).sum();
+
+    table.push_str(&format!(
+        "Total : {} solutions of {} problems took {:.2?}\n",
+        records.len(),
+        problems.len(),
+        total_elapsed
+    ));
+
+    if !slowest.is_empty() {
+        table.push_str("Slowest (*) :\n");
+        for index in slowest {
+            let record: &Record = &records[index];
+            table.push_str(&format!(
+                "  Problem {} {} took {:.2?}\n",
+                record.problem, record.solution, record.elapsed
+            ));
+        }
+    }
+
+    return table;
+}
+
+/// Escapes `s` so that it can be used inside a JSON string.
+fn escape_json(s: &str) -> String {
+    let mut escaped: String = String::new();
+
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    return escaped;
+}
+
+/// Quotes `s` if it contains a character that has a special meaning in CSV.
+fn escape_csv(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", s.replace('"', "\"\""));
+    }
+
+    return s.to_string();
+}
+
+/// Returns the time of every timed run of `measurement` in nanoseconds, joined by `separator`.
+fn samples_ns(measurement: &Measurement, separator: &str) -> String {
+    return measurement
+        .samples
+        .iter()
+        .map(|sample: &Duration| sample.as_nanos().to_string())
+        .collect::<Vec<String>>()
+        .join(separator);
+}
+
+/// Returns the records as a JSON array, with one object per line.
+///
+/// Each object has the fields `problem`, `solution`, `answer`, `elapsed_ns` and `status`, followed
+/// by `min_ns`, `mean_ns`, `stddev_ns` and `samples_ns` when the solution was timed, and by
+/// `allocations`, `allocated_bytes` and `peak_bytes` when the allocations were counted.
+/// `elapsed_ns` is the median of `samples_ns`. The answer is always a string, as it can be larger
+/// than what JSON numbers can hold exactly.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::answer::Answer;
+/// use project_euler::allocation::AllocationStats;
+/// use project_euler::problem::Params;
+/// use project_euler::report::{to_json, Record, Status};
+/// use project_euler::timing::Measurement;
+/// use std::time::Duration;
+///
+/// let samples: Vec<Duration> = [400, 500, 600].map(Duration::from_nanos).to_vec();
+///
+/// let records: Vec<Record> = vec![
+///     Record {
+///         problem: 3,
+///         solution: "solution_1",
+///         params: Params::default(),
+///         answer: Answer::Integer(6857),
+///         elapsed: Duration::from_nanos(2889400),
+///         status: Status::Solved,
+///         measurement: None,
+///         allocations: None,
+///     },
+///     Record {
+///         problem: 27,
+///         solution: "solution_1",
+///         params: Params::default(),
+///         answer: Answer::Tuple(vec![("a", -61), ("b", 971)]),
+///         elapsed: Duration::from_nanos(500),
+///         status: Status::Solved,
+///         measurement: Some(Measurement::new("solution_1", samples.clone())),
+///         allocations: Some(AllocationStats {
+///             allocations: 2,
+///             allocated_bytes: 96,
+///             peak_bytes: 64,
+///         }),
+///     },
+/// ];
+///
+/// assert_eq!(
+///     to_json(&records),
+///     "[\n\
+///     {\"problem\":3,\"solution\":\"solution_1\",\"answer\":\"6857\",\"elapsed_ns\":2889400,\"status\":\"SOLVED\"},\n\
+///     {\"problem\":27,\"solution\":\"solution_1\",\"answer\":\"a : -61, b : 971\",\"elapsed_ns\":500,\"status\":\"SOLVED\",\
+///     \"min_ns\":400,\"mean_ns\":500,\"stddev_ns\":100,\"samples_ns\":[400,500,600],\
+///     \"allocations\":2,\"allocated_bytes\":96,\"peak_bytes\":64}\n\
+///     ]\n"
+/// );
+/// assert_eq!(to_json(&[]), "[\n]\n");
+/// ```
+pub fn to_json(records: &[Record]) -> String {
+    let objects: Vec<String> = records.iter().map(to_json_object).collect();
+
+    if objects.is_empty() {
+        return String::from("[\n]\n");
+    }
+
+    return format!("[\n{}\n]\n", objects.join(",\n"));
+}
+
+/// Returns a single record as a JSON object on one line, with the fields described in [`to_json`].
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::answer::Answer;
+/// use project_euler::problem::Params;
+/// use project_euler::report::{to_json_object, Record, Status};
+/// use std::time::Duration;
+///
+/// let record: Record = Record {
+///     problem: 3,
+///     solution: "solution_1",
+///     params: Params::default(),
+///     answer: Answer::Integer(6857),
+///     elapsed: Duration::from_nanos(2889400),
+///     status: Status::Solved,
+///     measurement: None,
+///     allocations: None,
+/// };
+///
+/// assert_eq!(
+///     to_json_object(&record),
+///     "{\"problem\":3,\"solution\":\"solution_1\",\"answer\":\"6857\",\"elapsed_ns\":2889400,\"status\":\"SOLVED\"}"
+/// );
+/// ```
+pub fn to_json_object(record: &Record) -> String {
+    let measurement: String = match &record.measurement {
+        Some(measurement) => format!(
+            ",\"min_ns\":{},\"mean_ns\":{},\"stddev_ns\":{},\"samples_ns\":[{}]",
+            measurement.min.as_nanos(),
+            measurement.mean.as_nanos(),
+            measurement.stddev.as_nanos(),
+            samples_ns(measurement, ",")
+        ),
+        None => String::new(),
+    };
+
+    let allocations: String = match record.allocations {
+        Some(stats) => format!(
+            ",\"allocations\":{},\"allocated_bytes\":{},\"peak_bytes\":{}",
+            stats.allocations, stats.allocated_bytes, stats.peak_bytes
+        ),
+        None => String::new(),
+    };
+
+    return format!(
+        "{{\"problem\":{},\"solution\":\"{}\",\"answer\":\"{}\",\"elapsed_ns\":{},\"status\":\"{}\"{}{}}}",
+        record.problem,
+        escape_json(record.solution),
+        escape_json(&record.answer.to_string()),
+        record.elapsed.as_nanos(),
+        record.status,
+        measurement,
+        allocations
+    );
+}
+
+/// Returns the records as CSV with the header
+/// `problem,solution,answer,elapsed_ns,status,allocations,allocated_bytes,peak_bytes,min_ns,mean_ns,stddev_ns,samples_ns`.
+///
+/// The allocation fields are empty when the allocations were not counted, and the last four
+/// fields when the solution was not timed. `elapsed_ns` is the median of `samples_ns`, which are
+/// separated by `;`.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::answer::Answer;
+/// use project_euler::allocation::AllocationStats;
+/// use project_euler::problem::Params;
+/// use project_euler::report::{to_csv, Record, Status};
+/// use project_euler::timing::Measurement;
+/// use std::time::Duration;
+///
+/// let samples: Vec<Duration> = [400, 500, 600].map(Duration::from_nanos).to_vec();
+///
+/// let records: Vec<Record> = vec![
+///     Record {
+///         problem: 3,
+///         solution: "solution_1",
+///         params: Params::default(),
+///         answer: Answer::Integer(6857),
+///         elapsed: Duration::from_nanos(2889400),
+///         status: Status::Solved,
+///         measurement: None,
+///         allocations: None,
+///     },
+///     Record {
+///         problem: 27,
+///         solution: "solution_1",
+///         params: Params::default(),
+///         answer: Answer::Tuple(vec![("a", -61), ("b", 971)]),
+///         elapsed: Duration::from_nanos(500),
+///         status: Status::Solved,
+///         measurement: Some(Measurement::new("solution_1", samples.clone())),
+///         allocations: Some(AllocationStats {
+///             allocations: 2,
+///             allocated_bytes: 96,
+///             peak_bytes: 64,
+///         }),
+///     },
+/// ];
+///
+/// assert_eq!(
+///     to_csv(&records),
+///     "problem,solution,answer,elapsed_ns,status,allocations,allocated_bytes,peak_bytes,\
+///     min_ns,mean_ns,stddev_ns,samples_ns\n\
+///     3,solution_1,6857,2889400,SOLVED,,,,,,,\n\
+///     27,solution_1,\"a : -61, b : 971\",500,SOLVED,2,96,64,400,500,100,400;500;600\n"
+/// );
+/// ```
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv: String = String::from(
+        "problem,solution,answer,elapsed_ns,status,allocations,allocated_bytes,peak_bytes,min_ns,mean_ns,stddev_ns,samples_ns\n",
+    );
+
+    for record in records {
+        let allocations: String = match record.allocations {
+            Some(stats) => format!(
+                "{},{},{}",
+                stats.allocations, stats.allocated_bytes, stats.peak_bytes
+            ),
+            None => String::from(",,"),
+        };
+
+        let measurement: String = match &record.measurement {
+            Some(measurement) => format!(
+                "{},{},{},{}",
+                measurement.min.as_nanos(),
+                measurement.mean.as_nanos(),
+                measurement.stddev.as_nanos(),
+                samples_ns(measurement, ";")
+            ),
+            None => String::from(",,,"),
+        };
+
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            record.problem,
+            escape_csv(record.solution),
+            escape_csv(&record.answer.to_string()),
+            record.elapsed.as_nanos(),
+            record.status,
+            allocations,
+            measurement
+        ));
+    }
+
+    return csv;
+}
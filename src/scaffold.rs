@@ -0,0 +1,203 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Root of the crate, where new problem modules are written.
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Returns the source of a new problem module, with a placeholder `solution_1` and a test
+/// asserting its answer.
+///
+/// ### Arguments
+///
+/// * `number` : `u32` - The problem number.
+/// * `title` : `&str` - The title of the problem.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::scaffold::module_source as f;
+///
+/// let source: String = f(32, "Pandigital Products");
+///
+/// assert!(source.starts_with("// https://projecteuler.net/problem=32\n"));
+/// assert!(source.contains("pub struct Problem32;"));
+/// assert!(source.contains("return \"Pandigital Products\";"));
+/// assert!(source.contains("fn solution_1() -> Answer {"));
+/// assert!(source.contains("fn test_solution_1() {"));
+/// ```
+pub fn module_source(number: u32, title: &str) -> String {
+    return format!(
+        "\
+// https://projecteuler.net/problem={number}
+
+use crate::answer::Answer;
+use crate::problem::{{Problem, Solution}};
+
+#[allow(dead_code)]
+fn solution_1() -> Answer {{
+    return Answer::Integer(0);
+}}
+
+pub struct Problem{number};
+
+impl Problem for Problem{number} {{
+    fn number(&self) -> u32 {{
+        return {number};
+    }}
+
+    fn title(&self) -> &'static str {{
+        return \"{title}\";
+    }}
+
+    fn solutions(&self) -> Vec<Solution> {{
+        return vec![Solution::new(\"solution_1\", |_| solution_1())];
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    #[ignore = \"placeholder, replace the expected answer once the problem is solved\"]
+    fn test_solution_1() {{
+        assert_eq!(solution_1(), Answer::Integer(0));
+    }}
+}}
+",
+        number = number,
+        title = title.replace('\\', "\\\\").replace('"', "\\\""),
+    );
+}
+
+/// Registers the module of problem `number` in the contents of `src/problems/mod.rs`.
+///
+/// The `pub mod` declaration is inserted in the order `rustfmt` keeps them in, and the problem is
+/// inserted in [`PROBLEMS`](crate::problems::PROBLEMS) in ascending order of problem number.
+///
+/// ### Arguments
+///
+/// * `mod_rs` : `&str` - Contents of `src/problems/mod.rs`.
+/// * `number` : `u32` - The problem number.
+///
+/// ### Returns
+///
+/// * `Result<String, String>` - The new contents, or a message if the problem is already
+///   registered or the registry cannot be found.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::scaffold::register as f;
+///
+/// let mod_rs: &str = "\
+/// pub mod problem_1;
+/// pub mod problem_2;
+///
+/// pub static PROBLEMS: &[&dyn Problem] = &[
+///     &problem_1::Problem1,
+///     &problem_2::Problem2,
+/// ];
+/// ";
+///
+/// assert_eq!(
+///     f(mod_rs, 10).unwrap(),
+///     "\
+/// pub mod problem_1;
+/// pub mod problem_10;
+/// pub mod problem_2;
+///
+/// pub static PROBLEMS: &[&dyn Problem] = &[
+///     &problem_1::Problem1,
+///     &problem_2::Problem2,
+///     &problem_10::Problem10,
+/// ];
+/// "
+/// );
+///
+/// assert!(f(mod_rs, 2).is_err());
+/// assert!(f("", 2).is_err());
+/// ```
+pub fn register(mod_rs: &str, number: u32) -> Result<String, String> {
+    let module: String = format!("pub mod problem_{};", number);
+    let entry: String = format!("    &problem_{}::Problem{},", number, number);
+
+    let mut lines: Vec<String> = mod_rs.lines().map(String::from).collect();
+
+    if lines.contains(&module) {
+        return Err(format!("problem {} is already registered", number));
+    }
+
+    let module_lines: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod problem_"))
+        .collect();
+    let (first, last) = match (module_lines.first(), module_lines.last()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return Err(String::from("no `pub mod problem_N;` declarations found")),
+    };
+
+    let mut modules: Vec<String> = lines[first..=last].to_vec();
+    modules.push(module);
+    modules.sort_by_key(|line: &String| line.trim_end_matches(';').to_string());
+    lines.splice(first..=last, modules);
+
+    let start: usize = match lines.iter().position(|line| line.contains("PROBLEMS")) {
+        Some(start) => start + 1,
+        None => return Err(String::from("`PROBLEMS` not found")),
+    };
+    let end: usize = match lines[start..]
+        .iter()
+        .position(|line| line.starts_with("];"))
+    {
+        Some(offset) => start + offset,
+        None => return Err(String::from("end of `PROBLEMS` not found")),
+    };
+
+    let index: usize = (start..end)
+        .find(|&i| {
+            let registered: Option<u32> = lines[i]
+                .trim()
+                .strip_prefix("&problem_")
+                .and_then(|rest| rest.split("::").next())
+                .and_then(|n| n.parse::<u32>().ok());
+
+            return registered.is_some_and(|registered| registered > number);
+        })
+        .unwrap_or(end);
+    lines.insert(index, entry);
+
+    return Ok(lines.join("\n") + "\n");
+}
+
+/// Creates `src/problems/problem_<number>.rs` and registers it in `src/problems/mod.rs`.
+///
+/// ### Arguments
+///
+/// * `crate_dir` : `&Path` - Root of the crate, usually [`CRATE_DIR`].
+/// * `number` : `u32` - The problem number.
+/// * `title` : `&str` - The title of the problem.
+///
+/// ### Returns
+///
+/// * `Result<PathBuf, String>` - The path of the new module, or a message if the module already
+///   exists or a file cannot be read or written.
+pub fn create(crate_dir: &Path, number: u32, title: &str) -> Result<PathBuf, String> {
+    let problems_dir: PathBuf = crate_dir.join("src").join("problems");
+    let module_path: PathBuf = problems_dir.join(format!("problem_{}.rs", number));
+    let mod_rs_path: PathBuf = problems_dir.join("mod.rs");
+
+    if module_path.exists() {
+        return Err(format!("{} already exists", module_path.display()));
+    }
+
+    let mod_rs: String = fs::read_to_string(&mod_rs_path)
+        .map_err(|error| format!("cannot read {} : {}", mod_rs_path.display(), error))?;
+    let mod_rs: String = register(&mod_rs, number)?;
+
+    fs::write(&module_path, module_source(number, title))
+        .map_err(|error| format!("cannot write {} : {}", module_path.display(), error))?;
+    fs::write(&mod_rs_path, mod_rs)
+        .map_err(|error| format!("cannot write {} : {}", mod_rs_path.display(), error))?;
+
+    return Ok(module_path);
+}
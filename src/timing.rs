@@ -0,0 +1,286 @@
+use std::fmt;
+use std::hint;
+use std::time::{Duration, Instant};
+
+/// Number of untimed runs before the timed ones, used by [`time_solutions!`](crate::time_solutions)
+/// when no count is given and by the default [`Sampling`].
+pub const DEFAULT_WARM_UP: usize = 1;
+
+/// Number of timed runs, used by [`time_solutions!`](crate::time_solutions) when no count is given
+/// and by the default [`Sampling`].
+pub const DEFAULT_REPEATS: usize = 10;
+
+/// Number of untimed and timed runs of every solution, set with `--warm-up` and `--repeats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sampling {
+    /// Number of untimed runs, to fill caches and let the CPU clock up.
+    pub warm_up: usize,
+    /// Number of timed runs, at least 1.
+    pub repeats: usize,
+}
+
+impl Sampling {
+    /// A single timed run without warm-up, which is how the problems are run by default.
+    pub const ONCE: Sampling = Sampling {
+        warm_up: 0,
+        repeats: 1,
+    };
+}
+
+impl Default for Sampling {
+    /// [`DEFAULT_WARM_UP`] untimed runs and [`DEFAULT_REPEATS`] timed runs, which is how the
+    /// problems are run by `compare` and `--save`, as the median of a single run is too noisy to
+    /// compare.
+    fn default() -> Sampling {
+        return Sampling {
+            warm_up: DEFAULT_WARM_UP,
+            repeats: DEFAULT_REPEATS,
+        };
+    }
+}
+
+/// Runs `func` `warm_up` times without timing it, then `repeats` times timing every run.
+///
+/// ### Arguments
+///
+/// * `warm_up` : `usize` - Number of untimed runs, to fill caches and let the CPU clock up.
+/// * `repeats` : `usize` - Number of timed runs.
+/// * `func` : `impl FnMut() -> T` - The function to time.
+///
+/// ### Returns
+///
+/// * `(T, Vec<Duration>)` - The result of the last run and the time taken by every timed run.
+///
+/// ### Panics
+///
+/// When `repeats` is 0.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::timing::sample;
+///
+/// let mut n_calls: usize = 0;
+/// let (result, samples) = sample(2, 5, || {
+///     n_calls += 1;
+///     return n_calls;
+/// });
+///
+/// assert_eq!(result, 7);
+/// assert_eq!(samples.len(), 5);
+/// ```
+pub fn sample<T>(
+    warm_up: usize,
+    repeats: usize,
+    mut func: impl FnMut() -> T,
+) -> (T, Vec<Duration>) {
+    if repeats == 0 {
+        panic!("repeats cannot be 0.");
+    }
+
+    for _ in 0..warm_up {
+        hint::black_box(func());
+    }
+
+    let mut samples: Vec<Duration> = Vec::with_capacity(repeats);
+    let mut result: Option<T> = None;
+
+    for _ in 0..repeats {
+        let start_time: Instant = Instant::now();
+        let value: T = hint::black_box(func());
+        samples.push(start_time.elapsed());
+
+        result = Some(value);
+    }
+
+    return (result.unwrap(), samples);
+}
+
+/// Statistics of the timed runs of a single function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub name: &'static str,
+    /// Time taken by every timed run, in the order they were run.
+    pub samples: Vec<Duration>,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Sample standard deviation, which is 0 for a single run.
+    pub stddev: Duration,
+}
+
+impl Measurement {
+    /// Computes the statistics of `samples`.
+    ///
+    /// ### Panics
+    ///
+    /// When `samples` is empty.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::timing::Measurement;
+    /// use std::time::Duration;
+    ///
+    /// let samples: Vec<Duration> = [4, 1, 3, 2, 5].map(Duration::from_micros).to_vec();
+    /// let measurement: Measurement = Measurement::new("solution_1", samples);
+    ///
+    /// assert_eq!(measurement.min, Duration::from_micros(1));
+    /// assert_eq!(measurement.median, Duration::from_micros(3));
+    /// assert_eq!(measurement.mean, Duration::from_micros(3));
+    /// assert_eq!(measurement.stddev.as_nanos(), 1581);
+    ///
+    /// let samples: Vec<Duration> = [4, 1].map(Duration::from_micros).to_vec();
+    /// assert_eq!(Measurement::new("solution_1", samples).median, Duration::from_nanos(2500));
+    /// ```
+    pub fn new(name: &'static str, samples: Vec<Duration>) -> Measurement {
+        if samples.is_empty() {
+            panic!("samples cannot be empty.");
+        }
+
+        let mut sorted: Vec<Duration> = samples.clone();
+        sorted.sort();
+
+        let n: usize = sorted.len();
+        let median: Duration = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let mean_ns: f64 = sorted
+            .iter()
+            .map(|d: &Duration| d.as_nanos() as f64)
+            .sum::<f64>()
+            / n as f64;
+
+        let stddev_ns: f64 = if n > 1 {
+            let sum_of_squares: f64 = sorted
+                .iter()
+                .map(|d: &Duration| (d.as_nanos() as f64 - mean_ns).powi(2))
+                .sum();
+            (sum_of_squares / (n - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+
+        return Measurement {
+            name,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean_ns.round() as u64),
+            stddev: Duration::from_nanos(stddev_ns.round() as u64),
+            samples,
+        };
+    }
+}
+
+impl fmt::Display for Measurement {
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::timing::Measurement;
+    /// use std::time::Duration;
+    ///
+    /// let samples: Vec<Duration> = [4, 1, 3, 2, 5].map(Duration::from_micros).to_vec();
+    ///
+    /// assert_eq!(
+    ///     Measurement::new("solution_1", samples).to_string(),
+    ///     "solution_1 took 3µs (min 1µs, mean 3µs ± 1.581µs, 5 runs)"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(
+            f,
+            "{} took {:?} (min {:?}, mean {:?} ± {:?}, {} runs)",
+            self.name,
+            self.median,
+            self.min,
+            self.mean,
+            self.stddev,
+            self.samples.len()
+        );
+    }
+}
+
+/// Returns an error listing every function with its result if the results of the given functions
+/// are not all the same.
+///
+/// Used by [`report::disagreements`](crate::report::disagreements) to check that the variants of
+/// every problem that is run agree.
+///
+/// ### Arguments
+///
+/// * `results` : `&[(&str, String)]` - Name of every function with the representation of its
+///   result.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::timing::check_agreement;
+///
+/// assert!(check_agreement(&[("solution_1", String::from("6857"))]).is_ok());
+/// assert_eq!(
+///     check_agreement(&[
+///         ("solution_1", String::from("6857")),
+///         ("solution_2", String::from("1471")),
+///     ]),
+///     Err(String::from(
+///         "Solutions disagree : solution_1 returned 6857, solution_2 returned 1471."
+///     ))
+/// );
+/// ```
+pub fn check_agreement(results: &[(&str, String)]) -> Result<(), String> {
+    let is_agreed: bool = results.windows(2).all(|pair| pair[0].1 == pair[1].1);
+
+    if !is_agreed {
+        let results: Vec<String> = results
+            .iter()
+            .map(|(name, result)| format!("{} returned {}", name, result))
+            .collect();
+
+        return Err(format!("Solutions disagree : {}.", results.join(", ")));
+    }
+
+    return Ok(());
+}
+
+/// Panics if the results of the given functions are not all the same.
+///
+/// Used by [`time_solutions!`](crate::time_solutions) with `check` to turn the variants of a
+/// solution into a differential test of each other.
+///
+/// ### Arguments
+///
+/// * `results` : `&[(&str, String)]` - Name of every function with the `Debug` representation of
+///   its result.
+///
+/// ### Panics
+///
+/// When any result differs from the first one, with the message of [`check_agreement`].
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::timing::assert_agreement;
+///
+/// assert_agreement(&[
+///     ("solution_1", String::from("6857")),
+///     ("solution_2", String::from("6857")),
+/// ]);
+/// assert_agreement(&[]);
+/// ```
+///
+/// ```should_panic
+/// use project_euler::timing::assert_agreement;
+///
+/// assert_agreement(&[
+///     ("solution_1", String::from("6857")),
+///     ("solution_2", String::from("1471")),
+/// ]);
+/// ```
+pub fn assert_agreement(results: &[(&str, String)]) {
+    if let Err(message) = check_agreement(results) {
+        panic!("{}", message);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::hash;
+use crate::report::{Record, Status};
+use std::collections::HashMap;
+
+/// Contents of `files/answer_digests.txt`, embedded at compile time.
+pub const ANSWER_DIGESTS: &str = include_str!("../files/answer_digests.txt");
+
+/// Digest of a known answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Digest {
+    Md5([u8; 16]),
+    Sha256([u8; 32]),
+}
+
+impl Digest {
+    /// Parses a digest written as `<algorithm>:<hex digest>`, where `<algorithm>` is `md5` or
+    /// `sha256`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::verify::Digest;
+    ///
+    /// assert!(Digest::parse("md5:900150983cd24fb0d6963f7d28e17f72").is_ok());
+    /// assert!(Digest::parse(
+    ///     "sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    /// ).is_ok());
+    ///
+    /// assert!(Digest::parse("900150983cd24fb0d6963f7d28e17f72").is_err());
+    /// assert!(Digest::parse("sha1:a9993e364706816aba3e25717850c26c9cd0d89d").is_err());
+    /// assert!(Digest::parse("md5:9001").is_err());
+    /// ```
+    pub fn parse(digest: &str) -> Result<Digest, String> {
+        let (algorithm, hex) = match digest.split_once(':') {
+            Some(parts) => parts,
+            None => return Err(format!("missing algorithm in `{}`", digest)),
+        };
+
+        let bytes: Vec<u8> = match hash::from_hex(hex) {
+            Some(bytes) => bytes,
+            None => return Err(format!("invalid hex digest `{}`", hex)),
+        };
+
+        return match (algorithm, bytes.len()) {
+            ("md5", 16) => Ok(Digest::Md5(bytes.try_into().unwrap())),
+            ("sha256", 32) => Ok(Digest::Sha256(bytes.try_into().unwrap())),
+            ("md5" | "sha256", length) => Err(format!(
+                "{} digest cannot be {} bytes long",
+                algorithm, length
+            )),
+            _ => Err(format!("unknown algorithm `{}`", algorithm)),
+        };
+    }
+
+    /// Returns whether `answer` hashes to this digest.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use project_euler::verify::Digest;
+    ///
+    /// let digest: Digest = Digest::parse("md5:900150983cd24fb0d6963f7d28e17f72").unwrap();
+    ///
+    /// assert!(digest.matches("abc"));
+    /// assert!(!digest.matches("abd"));
+    /// ```
+    pub fn matches(&self, answer: &str) -> bool {
+        return match self {
+            Digest::Md5(digest) => hash::md5(answer.as_bytes()) == *digest,
+            Digest::Sha256(digest) => hash::sha256(answer.as_bytes()) == *digest,
+        };
+    }
+}
+
+/// Result of comparing the answer of a solution with the known answer of the problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    /// There is no known answer for the problem.
+    Unknown,
+}
+
+/// Parses an answer digests file into a map of problem number to digest.
+///
+/// Each non-empty line that does not start with `#` is `<problem number> <algorithm>:<hex digest>`.
+///
+/// ### Arguments
+///
+/// * `content` : `&str` - Contents of the answer digests file.
+///
+/// ### Returns
+///
+/// * `Result<HashMap<u32, Digest>, String>` - Digests keyed by problem number, or a message
+///   describing the first invalid line.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::verify::parse_answer_digests as f;
+///
+/// let digests = f("# comment\n1 md5:900150983cd24fb0d6963f7d28e17f72\n\n").unwrap();
+///
+/// assert_eq!(digests.len(), 1);
+/// assert!(digests[&1].matches("abc"));
+///
+/// assert!(f("one md5:900150983cd24fb0d6963f7d28e17f72").is_err());
+/// assert!(f("1").is_err());
+/// assert!(f("1 md5:00").is_err());
+/// assert!(f("1 md5:900150983cd24fb0d6963f7d28e17f72\n1 md5:900150983cd24fb0d6963f7d28e17f72").is_err());
+/// ```
+pub fn parse_answer_digests(content: &str) -> Result<HashMap<u32, Digest>, String> {
+    let mut digests: HashMap<u32, Digest> = HashMap::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line: &str = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (problem, digest) = match line.split_once(char::is_whitespace) {
+            Some((problem, digest)) => (problem, digest.trim()),
+            None => return Err(format!("line {} : missing digest", index + 1)),
+        };
+
+        let problem: u32 = match problem.parse::<u32>() {
+            Ok(problem) => problem,
+            Err(_) => {
+                return Err(format!(
+                    "line {} : invalid problem number `{}`",
+                    index + 1,
+                    problem
+                ))
+            }
+        };
+
+        let digest: Digest = match Digest::parse(digest) {
+            Ok(digest) => digest,
+            Err(message) => return Err(format!("line {} : {}", index + 1, message)),
+        };
+
+        if digests.insert(problem, digest).is_some() {
+            return Err(format!(
+                "line {} : duplicate digest for problem {}",
+                index + 1,
+                problem
+            ));
+        }
+    }
+
+    return Ok(digests);
+}
+
+/// Compares the digest of the answer in `record` with the known digest of its problem.
+///
+/// A solution that did not return an answer, like one that timed out, fails.
+///
+/// ### Examples
+///
+/// ```
+/// use project_euler::answer::Answer;
+/// use project_euler::problem::Params;
+/// use project_euler::report::{Record, Status};
+/// use project_euler::verify::{verify, Digest, Verdict};
+/// use std::collections::HashMap;
+/// use std::time::Duration;
+///
+/// let digests: HashMap<u32, Digest> = HashMap::from([(
+///     1,
+///     Digest::parse("sha256:c0b20f4665d0388d564f0b6ecf3edc9f9480cb15fff87198b95701d9f5fe1f7b")
+///         .unwrap(),
+/// )]);
+///
+/// let mut record: Record = Record {
+///     problem: 1,
+///     solution: "solution_1",
+///     params: Params::default(),
+///     answer: Answer::Integer(233168),
+///     elapsed: Duration::ZERO,
+///     status: Status::Solved,
+///     measurement: None,
+///     allocations: None,
+/// };
+/// assert_eq!(verify(&record, &digests), Verdict::Pass);
+///
+/// record.answer = Answer::Integer(233167);
+/// assert_eq!(verify(&record, &digests), Verdict::Fail);
+///
+/// record.answer = Answer::Text(String::new());
+/// record.status = Status::Timeout;
+/// assert_eq!(verify(&record, &digests), Verdict::Fail);
+///
+/// record.problem = 2;
+/// assert_eq!(verify(&record, &digests), Verdict::Unknown);
+/// ```
+pub fn verify(record: &Record, answer_digests: &HashMap<u32, Digest>) -> Verdict {
+    return match answer_digests.get(&record.problem) {
+        Some(_) if record.status != Status::Solved => Verdict::Fail,
+        Some(digest) if digest.matches(&record.answer.to_string()) => Verdict::Pass,
+        Some(_) => Verdict::Fail,
+        None => Verdict::Unknown,
+    };
+}